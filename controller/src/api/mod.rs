@@ -0,0 +1,90 @@
+// Módulo de versionamento da API: /api/v2 é a versão atual; /api/v1 e /api sem versão mantêm os formatos antigos
+use axum::extract::{OriginalUri, Request, State};
+use axum::http::{header, HeaderName, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::borrow::Cow;
+
+const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+// Rota sem o prefixo de versão ("/api/v2/processes" -> "/api/processes"), usada por limites e auditoria
+pub fn canonical_route(route: &str) -> Cow<'_, str> {
+    for prefix in ["/api/v1/", "/api/v2/"] {
+        if let Some(rest) = route.strip_prefix(prefix) {
+            return Cow::Owned(format!("/api/{}", rest));
+        }
+    }
+    Cow::Borrowed(route)
+}
+
+#[derive(Clone, Debug)]
+pub struct Deprecation {
+    deprecated: HeaderValue,
+    sunset: HeaderValue,
+}
+
+fn parse_date(key: &str, value: &str) -> Result<chrono::DateTime<chrono::Utc>, anyhow::Error> {
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| anyhow::anyhow!("{}: '{}' não é uma data no formato AAAA-MM-DD", key, value))?;
+    Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+}
+
+impl Deprecation {
+    // Datas no formato AAAA-MM-DD (api.v1_deprecated e api.v1_sunset); a depreciação precisa vir antes do sunset
+    pub fn new(deprecated: &str, sunset: &str) -> Result<Self, anyhow::Error> {
+        let deprecated_at = parse_date("api.v1_deprecated", deprecated)?;
+        let sunset_at = parse_date("api.v1_sunset", sunset)?;
+        if deprecated_at >= sunset_at {
+            anyhow::bail!("api.v1_deprecated: {} precisa ser anterior a api.v1_sunset ({})", deprecated, sunset);
+        }
+        Ok(Deprecation {
+            // Deprecation usa o timestamp Unix ("@1792281600", RFC 9745); Sunset usa data HTTP (RFC 8594)
+            deprecated: HeaderValue::from_str(&format!("@{}", deprecated_at.timestamp()))?,
+            sunset: HeaderValue::from_str(&sunset_at.format("%a, %d %b %Y %H:%M:%S GMT").to_string())?,
+        })
+    }
+}
+
+// Marca as respostas das rotas antigas com Deprecation, Sunset e um Link para a rota equivalente na v2
+pub async fn deprecated(State(deprecation): State<Deprecation>, request: Request, next: Next) -> Response {
+    // Dentro do nest a URI já vem sem o prefixo; o caminho completo fica em OriginalUri
+    let path = request.extensions().get::<OriginalUri>().map(|u| u.path().to_string()).unwrap_or_else(|| request.uri().path().to_string());
+    let successor = canonical_route(&path).replacen("/api/", "/api/v2/", 1);
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    headers.insert(DEPRECATION, deprecation.deprecated.clone());
+    headers.insert(SUNSET, deprecation.sunset.clone());
+    if let Ok(link) = HeaderValue::from_str(&format!("<{}>; rel=\"successor-version\"", successor)) {
+        headers.insert(header::LINK, link);
+    }
+    response
+}
+
+pub mod v1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_deprecation_and_sunset_headers() {
+        let deprecation = Deprecation::new("2026-10-18", "2027-04-30").unwrap();
+        assert_eq!(deprecation.deprecated, "@1792281600");
+        assert_eq!(deprecation.sunset, "Fri, 30 Apr 2027 00:00:00 GMT");
+    }
+
+    #[test]
+    fn deprecation_must_precede_sunset() {
+        assert!(Deprecation::new("2027-04-30", "2027-04-30").unwrap_err().to_string().starts_with("api.v1_deprecated"));
+        assert!(Deprecation::new("2027-05-01", "2027-04-30").is_err());
+        assert!(Deprecation::new("18/10/2026", "2027-04-30").unwrap_err().to_string().starts_with("api.v1_deprecated"));
+        assert!(Deprecation::new("2026-10-18", "2027-02-30").unwrap_err().to_string().starts_with("api.v1_sunset"));
+    }
+
+    #[test]
+    fn canonical_route_strips_version() {
+        assert_eq!(canonical_route("/api/v1/processes/{pid}"), "/api/processes/{pid}");
+        assert_eq!(canonical_route("/api/v2/audit"), "/api/audit");
+        assert_eq!(canonical_route("/api/system"), "/api/system");
+    }
+}
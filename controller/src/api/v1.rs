@@ -0,0 +1,150 @@
+// Formatos da v1, produzidos a partir dos tipos atuais para manter scripts antigos funcionando
+use serde::Serialize;
+
+use crate::fs::DiskInfo;
+use crate::process::{self, ResourceKind, ThreadInfo};
+use crate::system::{self, cpu, load, memory, power};
+
+// v1 expunha percentuais e uptime como texto já formatado ("12.3%", "5h 42m")
+#[derive(Serialize, Debug, Clone)]
+pub struct SystemInfo {
+    pub cpu_total: String,
+    pub cpu_per_core: Vec<f32>,
+    pub memory_total_mb: u64,
+    pub memory_used_mb: u64,
+    pub memory_free_mb: u64,
+    pub memory_percent: String,
+    pub uptime: String,
+    pub uptime_secs: u64,
+    pub process_count: usize,
+    pub cpu_base_speed_mhz: u64,
+    pub cpu_logical_processors: u32,
+    pub cpu_vendor: String,
+    pub cpu_brand: String,
+    pub cpu_physical_cores: u32,
+    pub disks: Vec<DiskInfo>,
+    pub os_name: String,
+    pub os_version: String,
+    pub os_build: String,
+    pub hostname: String,
+    pub boot_time: u64,
+    pub cpu_times: Option<cpu::CpuBreakdown>,
+    pub cpu_frequencies: Vec<cpu::CoreFrequency>,
+    pub load: Option<load::LoadInfo>,
+    pub memory_details: Option<memory::MemoryDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<power::PowerInfo>,
+}
+
+impl From<system::SystemInfo> for SystemInfo {
+    fn from(s: system::SystemInfo) -> Self {
+        SystemInfo {
+            cpu_total: format!("{:.1}%", s.cpu_total),
+            cpu_per_core: s.cpu_per_core,
+            memory_total_mb: s.memory_total_mb,
+            memory_used_mb: s.memory_used_mb,
+            memory_free_mb: s.memory_free_mb,
+            memory_percent: format!("{:.1}%", s.memory_percent),
+            uptime: format!("{}h {}m", s.uptime_secs / 3600, (s.uptime_secs % 3600) / 60),
+            uptime_secs: s.uptime_secs,
+            process_count: s.process_count,
+            cpu_base_speed_mhz: s.cpu_base_speed_mhz,
+            cpu_logical_processors: s.cpu_logical_processors,
+            cpu_vendor: s.cpu_vendor,
+            cpu_brand: s.cpu_brand,
+            cpu_physical_cores: s.cpu_physical_cores,
+            disks: s.disks,
+            os_name: s.os_name,
+            os_version: s.os_version,
+            os_build: s.os_build,
+            hostname: s.hostname,
+            boot_time: s.boot_time,
+            cpu_times: s.cpu_times,
+            cpu_frequencies: s.cpu_frequencies,
+            load: s.load,
+            memory_details: s.memory_details,
+            power: s.power,
+        }
+    }
+}
+
+// v1 devolvia a lista completa, com recursos abertos como texto ("Arquivo: Handle=0x1C")
+#[derive(Serialize, Debug, Clone)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    pub exe_path: Option<String>,
+    pub status: String,
+    pub username: String,
+    pub cpu: f32,
+    pub memory_kb: u64,
+    pub memory_percent: f32,
+    pub arch: String,
+    pub description: String,
+    pub page_faults: u32,
+    pub peak_working_set_kb: u64,
+    pub working_set_kb: u64,
+    pub pagefile_kb: u64,
+    pub io_read_bytes: Option<u64>,
+    pub io_write_bytes: Option<u64>,
+    pub io_read_ops: Option<u64>,
+    pub io_write_ops: Option<u64>,
+    pub handle_count: Option<u32>,
+    pub thread_count: Option<u32>,
+    pub parent_pid: Option<u32>,
+    pub priority: Option<i32>,
+    pub creation_time: Option<u64>,
+    pub session_id: Option<u32>,
+    pub command_line: Option<String>,
+    pub environment: Option<Vec<String>>,
+    pub threads: Vec<ThreadInfo>,
+    pub open_resources: Option<Vec<String>>,
+}
+
+fn resource_label(kind: ResourceKind) -> &'static str {
+    match kind {
+        ResourceKind::File => "Arquivo",
+        ResourceKind::Mutex => "Mutex",
+        ResourceKind::Semaphore => "Semáforo",
+        ResourceKind::Pipe => "Pipe",
+        ResourceKind::Socket => "Socket",
+        ResourceKind::Other => "Outro",
+    }
+}
+
+impl From<process::ProcessInfo> for ProcessInfo {
+    fn from(p: process::ProcessInfo) -> Self {
+        ProcessInfo {
+            pid: p.pid,
+            name: p.name,
+            exe_path: p.exe_path,
+            status: p.status,
+            username: p.username,
+            cpu: p.cpu,
+            memory_kb: p.memory_kb,
+            memory_percent: p.memory_percent,
+            arch: p.arch,
+            description: p.description,
+            page_faults: p.page_faults,
+            peak_working_set_kb: p.peak_working_set_kb,
+            working_set_kb: p.working_set_kb,
+            pagefile_kb: p.pagefile_kb,
+            io_read_bytes: p.io_read_bytes,
+            io_write_bytes: p.io_write_bytes,
+            io_read_ops: p.io_read_ops,
+            io_write_ops: p.io_write_ops,
+            handle_count: p.handle_count,
+            thread_count: p.thread_count,
+            parent_pid: p.parent_pid,
+            priority: p.priority,
+            creation_time: p.creation_time,
+            session_id: p.session_id,
+            command_line: p.command_line,
+            environment: p.environment,
+            threads: p.threads,
+            open_resources: p.open_resources.map(|resources| {
+                resources.iter().map(|r| format!("{}: Handle=0x{:X}", resource_label(r.kind), r.handle)).collect()
+            }),
+        }
+    }
+}
@@ -0,0 +1,306 @@
+// Módulo de auditoria: registra chamadas sensíveis em JSON lines com rotação por tamanho
+use axum::extract::{MatchedPath, Query, RawPathParams, Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::auth::{Identity, Role};
+use crate::config::AuditConfig;
+
+// Rotas sempre auditadas: leitura de arquivos, handles de processos e o próprio log
+const SENSITIVE_ROUTES: &[&str] = &[
+    "/api/filesystem/tail",
+    "/api/filesystem/tail/follow",
+    "/api/processes/{pid}/handles",
+    "/api/audit",
+    "/api/log/level",
+];
+
+#[derive(Serialize, ToSchema, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Success,
+    Denied,
+    Error,
+}
+
+#[derive(Serialize, ToSchema, Deserialize, Debug, Clone)]
+pub struct AuditEntry {
+    pub timestamp: i64,
+    pub identity: String,
+    pub role: Option<Role>,
+    pub method: String,
+    pub endpoint: String,
+    pub target: Option<String>,
+    pub params: BTreeMap<String, String>,
+    pub status: u16,
+    pub outcome: Outcome,
+    pub duration_ms: u64,
+}
+
+#[derive(Deserialize, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+pub struct AuditQuery {
+    pub identity: Option<String>,
+    pub endpoint: Option<String>,
+    pub since: Option<i64>,
+    pub limit: Option<usize>,
+}
+
+// Detalhes que o handler quer registrar além dos parâmetros da requisição (ex.: valor anterior de uma alteração);
+// vão como extensão da resposta e são juntados aos params da entrada. Uma resposta com detalhes é sempre auditada,
+// mesmo fora de SENSITIVE_ROUTES: é assim que o handler marca o que só ele sabe que foi sensível
+#[derive(Clone, Debug, Default)]
+pub struct AuditDetails(pub BTreeMap<String, String>);
+
+impl<const N: usize> From<[(&str, &str); N]> for AuditDetails {
+    fn from(pairs: [(&str, &str); N]) -> Self {
+        AuditDetails(pairs.into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect())
+    }
+}
+
+pub struct AuditLog {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: Mutex<Option<File>>,
+}
+
+impl AuditLog {
+    pub fn open(config: &AuditConfig) -> Result<Self, anyhow::Error> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.path)
+            .map_err(|e| anyhow::anyhow!("audit.path: não foi possível abrir {}: {}", config.path.display(), e))?;
+        Ok(AuditLog { path: config.path.clone(), max_bytes: config.max_bytes, max_files: config.max_files, file: Mutex::new(Some(file)) })
+    }
+
+    fn rotated(&self, n: usize) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", n));
+        PathBuf::from(name)
+    }
+
+    // audit.jsonl -> audit.jsonl.1 -> ... -> audit.jsonl.N; o mais antigo é descartado
+    fn rotate(&self) -> std::io::Result<File> {
+        let _ = std::fs::remove_file(self.rotated(self.max_files));
+        for n in (1..self.max_files).rev() {
+            let from = self.rotated(n);
+            if from.exists() {
+                std::fs::rename(&from, self.rotated(n + 1))?;
+            }
+        }
+        // Config::validate garante max_files >= 1
+        std::fs::rename(&self.path, self.rotated(1))?;
+        OpenOptions::new().create(true).append(true).open(&self.path)
+    }
+
+    pub fn append(&self, entry: &AuditEntry) -> std::io::Result<()> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+        let mut guard = self.file.lock().unwrap();
+        let full = match guard.as_ref() {
+            Some(file) => {
+                let size = file.metadata()?.len();
+                size > 0 && size + line.len() as u64 > self.max_bytes
+            }
+            None => false,
+        };
+        if full {
+            *guard = None;
+            *guard = Some(self.rotate()?);
+        }
+        // Se uma rotação anterior falhou, tenta reabrir o arquivo atual
+        let file = match guard.as_mut() {
+            Some(file) => file,
+            None => guard.insert(OpenOptions::new().create(true).append(true).open(&self.path)?),
+        };
+        file.write_all(&line)?;
+        file.sync_data()
+    }
+
+    // Lê o arquivo atual e os rotacionados, do mais recente para o mais antigo
+    pub fn query(&self, query: &AuditQuery) -> std::io::Result<Vec<AuditEntry>> {
+        let limit = query.limit.unwrap_or(100);
+        let mut result = Vec::new();
+        let files = std::iter::once(self.path.clone()).chain((1..=self.max_files).map(|n| self.rotated(n)));
+        for path in files {
+            let mut entries = match read_entries(&path) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+            entries.reverse();
+            for entry in entries {
+                if query.since.is_some_and(|since| entry.timestamp < since) {
+                    return Ok(result);
+                }
+                // O endpoint é comparado sem versão: /api/audit, /api/v1/audit e /api/v2/audit são a mesma rota
+                let other_endpoint = query.endpoint.as_ref().is_some_and(|e| crate::api::canonical_route(e) != crate::api::canonical_route(&entry.endpoint));
+                if query.identity.as_ref().is_some_and(|i| *i != entry.identity) || other_endpoint {
+                    continue;
+                }
+                result.push(entry);
+                if result.len() >= limit {
+                    return Ok(result);
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn read_entries(path: &Path) -> std::io::Result<Vec<AuditEntry>> {
+    let reader = BufReader::new(File::open(path)?);
+    // Linhas corrompidas (ex.: gravação interrompida) são ignoradas
+    Ok(reader.lines().map_while(Result::ok).filter_map(|l| serde_json::from_str(&l).ok()).collect())
+}
+
+// Decide antes do handler; leituras do ambiente de processos são marcadas pelo próprio handler com AuditDetails,
+// já que só ele sabe se o papel recebeu o ambiente. Pedidos sem redação entram aqui para registrar também os 403
+fn is_sensitive(endpoint: &str, params: &BTreeMap<String, String>) -> bool {
+    let route = crate::api::canonical_route(endpoint);
+    let unredacted = params.get("unredacted").is_some_and(|v| v == "true");
+    SENSITIVE_ROUTES.contains(&route.as_ref()) || (unredacted && (route == "/api/processes" || route == "/api/processes/{pid}"))
+}
+
+// Registra a chamada com identidade, alvo, parâmetros e resultado; falhas de escrita vão para o stderr
+pub async fn record(State(log): State<Arc<AuditLog>>, request: Request, next: Next) -> Response {
+    let Some(endpoint) = request.extensions().get::<MatchedPath>().map(|p| p.as_str().to_string()) else {
+        return next.run(request).await;
+    };
+    let (mut parts, body) = request.into_parts();
+    let mut params = Query::<BTreeMap<String, String>>::try_from_uri(&parts.uri).map(|q| q.0).unwrap_or_default();
+    if let Ok(path_params) = axum::RequestPartsExt::extract::<RawPathParams>(&mut parts).await {
+        for (key, value) in path_params.iter() {
+            params.insert(key.to_string(), value.to_string());
+        }
+    }
+    let sensitive = is_sensitive(&endpoint, &params);
+    let method = parts.method.to_string();
+    let started = Instant::now();
+    let response = next.run(Request::from_parts(parts, body)).await;
+    match response.extensions().get::<AuditDetails>() {
+        Some(AuditDetails(details)) => params.extend(details.clone()),
+        None if !sensitive => return response,
+        None => {}
+    }
+    // Preenchida pela autenticação; ausente nos 401
+    let identity = response.extensions().get::<Identity>().cloned();
+    let status = response.status();
+    let entry = AuditEntry {
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        identity: identity.as_ref().map(|i| i.name.clone()).unwrap_or_else(|| "-".to_string()),
+        role: identity.map(|i| i.role),
+        method,
+        endpoint,
+        target: params.get("pid").or_else(|| params.get("path")).cloned(),
+        params,
+        status: status.as_u16(),
+        outcome: match status.as_u16() {
+            401 | 403 => Outcome::Denied,
+            s if s >= 400 => Outcome::Error,
+            _ => Outcome::Success,
+        },
+        duration_ms: started.elapsed().as_millis() as u64,
+    };
+    // A gravação (com sync_data) bloqueia; fica fora das threads do runtime
+    let writer = log.clone();
+    let written = tokio::task::spawn_blocking(move || writer.append(&entry)).await.map_err(std::io::Error::other).and_then(|r| r);
+    if let Err(e) = written {
+        tracing::error!(path = %log.path.display(), error = %e, "falha ao gravar auditoria");
+    }
+    response
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testutil::TempDir;
+
+    fn open(name: &str, max_bytes: u64, max_files: usize) -> (AuditLog, TempDir) {
+        let dir = TempDir::new(name);
+        let config = AuditConfig { enabled: true, path: dir.path().join("audit.jsonl"), max_bytes, max_files };
+        (AuditLog::open(&config).unwrap(), dir)
+    }
+
+    fn entry(timestamp: i64, identity: &str, endpoint: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp,
+            identity: identity.to_string(),
+            role: Some(Role::Admin),
+            method: "GET".to_string(),
+            endpoint: endpoint.to_string(),
+            target: None,
+            params: BTreeMap::new(),
+            status: 200,
+            outcome: Outcome::Success,
+            duration_ms: 1,
+        }
+    }
+
+    fn query(identity: Option<&str>, endpoint: Option<&str>, since: Option<i64>, limit: Option<usize>) -> AuditQuery {
+        AuditQuery { identity: identity.map(String::from), endpoint: endpoint.map(String::from), since, limit }
+    }
+
+    fn timestamps(entries: &[AuditEntry]) -> Vec<i64> {
+        entries.iter().map(|e| e.timestamp).collect()
+    }
+
+    #[test]
+    fn query_filters_newest_first() {
+        let (log, _dir) = open("query", 1 << 20, 2);
+        log.append(&entry(1, "ops", "/api/v2/audit")).unwrap();
+        log.append(&entry(2, "ci", "/api/v2/filesystem/tail")).unwrap();
+        log.append(&entry(3, "ops", "/api/v2/filesystem/tail")).unwrap();
+        log.append(&entry(4, "ops", "/api/v2/audit")).unwrap();
+
+        assert_eq!(timestamps(&log.query(&query(None, None, None, None)).unwrap()), vec![4, 3, 2, 1]);
+        assert_eq!(timestamps(&log.query(&query(Some("ops"), None, None, None)).unwrap()), vec![4, 3, 1]);
+        assert_eq!(timestamps(&log.query(&query(None, Some("/api/v2/filesystem/tail"), None, None)).unwrap()), vec![3, 2]);
+        assert_eq!(timestamps(&log.query(&query(Some("ops"), Some("/api/v2/audit"), None, None)).unwrap()), vec![4, 1]);
+        assert_eq!(timestamps(&log.query(&query(None, None, Some(3), None)).unwrap()), vec![4, 3]);
+        // Filtro sem versão ou com a outra versão encontra as mesmas entradas
+        assert_eq!(timestamps(&log.query(&query(None, Some("/api/filesystem/tail"), None, None)).unwrap()), vec![3, 2]);
+        assert_eq!(timestamps(&log.query(&query(None, Some("/api/v1/audit"), None, None)).unwrap()), vec![4, 1]);
+        assert_eq!(timestamps(&log.query(&query(None, None, None, Some(2))).unwrap()), vec![4, 3]);
+    }
+
+    #[test]
+    fn rotates_by_size_and_queries_across_files() {
+        let line = serde_json::to_vec(&entry(0, "ops", "/api/v2/audit")).unwrap().len() as u64 + 1;
+        // Cabem duas entradas por arquivo; além do atual são mantidos dois rotacionados
+        let (log, _dir) = open("rotation", line * 2, 2);
+        for timestamp in 1..=7 {
+            log.append(&entry(timestamp, "ops", "/api/v2/audit")).unwrap();
+        }
+        assert!(log.rotated(1).exists() && log.rotated(2).exists() && !log.rotated(3).exists());
+        assert_eq!(read_entries(&log.path).unwrap().len(), 1);
+        // As duas entradas mais antigas saíram com o arquivo descartado
+        assert_eq!(timestamps(&log.query(&query(None, None, None, None)).unwrap()), vec![7, 6, 5, 4, 3]);
+        assert_eq!(timestamps(&log.query(&query(None, None, Some(5), None)).unwrap()), vec![7, 6, 5]);
+    }
+
+    #[test]
+    fn sensitive_routes_ignore_version() {
+        let none = BTreeMap::new();
+        let unredacted = BTreeMap::from([("unredacted".to_string(), "true".to_string())]);
+        assert!(is_sensitive("/api/v1/filesystem/tail", &none));
+        assert!(is_sensitive("/api/v2/processes/{pid}/handles", &none));
+        assert!(!is_sensitive("/api/v2/system", &none));
+        // A leitura comum do ambiente é marcada pelo handler; aqui só o pedido sem redação
+        assert!(!is_sensitive("/api/v2/processes/{pid}", &none));
+        assert!(is_sensitive("/api/v2/processes/{pid}", &unredacted));
+        assert!(is_sensitive("/api/v1/processes", &unredacted));
+    }
+}
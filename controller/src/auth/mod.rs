@@ -0,0 +1,168 @@
+// Módulo de autenticação: tokens bearer com hash SHA-256 na configuração e papéis viewer/admin
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::AuthConfig;
+
+// Admin inclui tudo o que viewer pode acessar
+#[derive(Serialize, ToSchema, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Viewer,
+    Admin,
+}
+
+// Identidade da requisição, inserida nas extensions pelo middleware de autenticação
+#[derive(Debug, Clone)]
+pub struct Identity {
+    pub name: String,
+    pub role: Role,
+}
+
+impl Identity {
+    // Sem autenticação configurada todas as requisições têm acesso total, como antes; Config::validate só aceita
+    // escutar em loopback nesse caso, então o acesso anônimo fica restrito à própria máquina
+    fn anonymous() -> Self {
+        Identity { name: "anonymous".to_string(), role: Role::Admin }
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.role >= Role::Admin
+    }
+}
+
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+#[derive(Clone)]
+pub struct Authenticator {
+    enabled: bool,
+    tokens: Arc<HashMap<String, Identity>>,
+}
+
+impl Authenticator {
+    pub fn from_config(auth: &AuthConfig) -> Self {
+        let tokens = auth
+            .tokens
+            .iter()
+            .map(|t| (t.sha256.to_ascii_lowercase(), Identity { name: t.name.clone(), role: t.role }))
+            .collect();
+        Authenticator { enabled: auth.enabled, tokens: Arc::new(tokens) }
+    }
+
+    fn identify(&self, request: &Request) -> Option<Identity> {
+        if !self.enabled {
+            return Some(Identity::anonymous());
+        }
+        let value = request.headers().get(header::AUTHORIZATION)?.to_str().ok()?;
+        let (scheme, token) = value.split_once(' ')?;
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return None;
+        }
+        self.tokens.get(&hash_token(token.trim())).cloned()
+    }
+}
+
+// Identifica o token, anota o nome do token no span da requisição e responde 401 quando ausente ou inválido.
+// A identidade também vai na resposta para a auditoria, que fica por fora para registrar os 401
+pub async fn authenticate(State(auth): State<Authenticator>, mut request: Request, next: Next) -> Response {
+    match auth.identify(&request) {
+        Some(identity) => {
+            tracing::Span::current().record("identity", identity.name.as_str());
+            request.extensions_mut().insert(identity.clone());
+            let mut response = next.run(request).await;
+            response.extensions_mut().insert(identity);
+            response
+        }
+        None => (StatusCode::UNAUTHORIZED, [(header::WWW_AUTHENTICATE, "Bearer")]).into_response(),
+    }
+}
+
+// Usado como route_layer nas rotas que exigem um papel mínimo; responde 403 se o token não tiver o papel
+pub async fn require_role(State(role): State<Role>, request: Request, next: Next) -> Response {
+    match request.extensions().get::<Identity>() {
+        Some(identity) if identity.role >= role => next.run(request).await,
+        _ => StatusCode::FORBIDDEN.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TokenConfig;
+    use axum::body::Body;
+    use axum::routing::get;
+    use axum::{Extension, Router};
+    use tower::ServiceExt;
+
+    fn authenticator(enabled: bool) -> Authenticator {
+        let token = |name: &str, secret: &str, role| TokenConfig { name: name.to_string(), sha256: hash_token(secret), role };
+        Authenticator::from_config(&AuthConfig { enabled, tokens: vec![token("grafana", "viewer-secret", Role::Viewer), token("ops", "admin-secret", Role::Admin)] })
+    }
+
+    fn request(authorization: Option<&str>, uri: &str) -> Request {
+        let builder = Request::builder().uri(uri);
+        let builder = match authorization {
+            Some(value) => builder.header(header::AUTHORIZATION, value),
+            None => builder,
+        };
+        builder.body(Body::empty()).unwrap()
+    }
+
+    fn who(auth: &Authenticator, authorization: Option<&str>) -> Option<(String, Role)> {
+        auth.identify(&request(authorization, "/")).map(|i| (i.name, i.role))
+    }
+
+    #[test]
+    fn parses_bearer_header() {
+        let auth = authenticator(true);
+        assert_eq!(who(&auth, Some("Bearer viewer-secret")), Some(("grafana".to_string(), Role::Viewer)));
+        // Esquema sem diferenciar maiúsculas e espaços em volta do token
+        assert_eq!(who(&auth, Some("bearer  admin-secret ")), Some(("ops".to_string(), Role::Admin)));
+        assert_eq!(who(&auth, Some("Bearer wrong-secret")), None);
+        assert_eq!(who(&auth, Some("Basic admin-secret")), None);
+        assert_eq!(who(&auth, Some("admin-secret")), None);
+        assert_eq!(who(&auth, None), None);
+    }
+
+    #[test]
+    fn anonymous_is_admin_when_disabled() {
+        // Mesmo com um header inválido: sem auth o token não é verificado
+        assert_eq!(who(&authenticator(false), Some("Bearer wrong-secret")), Some(("anonymous".to_string(), Role::Admin)));
+    }
+
+    fn router(auth: Authenticator) -> Router {
+        let admin = Router::new()
+            .route("/admin", get(|| async { "admin" }))
+            .route_layer(axum::middleware::from_fn_with_state(Role::Admin, require_role));
+        Router::new()
+            .route("/whoami", get(|Extension(identity): Extension<Identity>| async move { identity.name }))
+            .merge(admin)
+            .layer(axum::middleware::from_fn_with_state(auth, authenticate))
+    }
+
+    async fn status(auth: Authenticator, authorization: Option<&str>, uri: &str) -> StatusCode {
+        router(auth).oneshot(request(authorization, uri)).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn enforces_tokens_and_roles() {
+        let response = router(authenticator(true)).oneshot(request(Some("Bearer wrong-secret"), "/whoami")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+        assert_eq!(status(authenticator(true), None, "/admin").await, StatusCode::UNAUTHORIZED);
+
+        assert_eq!(status(authenticator(true), Some("Bearer viewer-secret"), "/whoami").await, StatusCode::OK);
+        assert_eq!(status(authenticator(true), Some("Bearer viewer-secret"), "/admin").await, StatusCode::FORBIDDEN);
+        assert_eq!(status(authenticator(true), Some("Bearer admin-secret"), "/admin").await, StatusCode::OK);
+        assert_eq!(status(authenticator(false), None, "/admin").await, StatusCode::OK);
+    }
+}
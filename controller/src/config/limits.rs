@@ -0,0 +1,59 @@
+// Middleware que aplica os limites por rota da configuração (tempo máximo e requisições simultâneas)
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+use super::EndpointLimit;
+
+#[derive(Clone)]
+struct RouteLimit {
+    timeout: Option<Duration>,
+    permits: Option<Arc<Semaphore>>,
+}
+
+#[derive(Clone, Default)]
+pub struct Limits(Arc<HashMap<String, RouteLimit>>);
+
+impl Limits {
+    pub fn from_config(limits: &BTreeMap<String, EndpointLimit>) -> Self {
+        let routes = limits
+            .iter()
+            .map(|(route, limit)| {
+                let limit = RouteLimit {
+                    timeout: limit.timeout_ms.map(Duration::from_millis),
+                    permits: limit.max_concurrent.map(|n| Arc::new(Semaphore::new(n))),
+                };
+                (route.clone(), limit)
+            })
+            .collect();
+        Limits(Arc::new(routes))
+    }
+}
+
+// Rotas sem limite passam direto; acima do limite de concorrência responde 429, ao estourar o tempo responde 504
+pub async fn enforce(State(limits): State<Limits>, request: Request, next: Next) -> Response {
+    // Os limites valem para a rota em todas as versões da API
+    let route = request.extensions().get::<MatchedPath>().map(|p| crate::api::canonical_route(p.as_str()).into_owned());
+    let Some(limit) = route.and_then(|r| limits.0.get(&r)).cloned() else {
+        return next.run(request).await;
+    };
+    let _permit = match limit.permits {
+        Some(permits) => match permits.try_acquire_owned() {
+            Ok(permit) => Some(permit),
+            Err(_) => return StatusCode::TOO_MANY_REQUESTS.into_response(),
+        },
+        None => None,
+    };
+    match limit.timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, next.run(request)).await {
+            Ok(response) => response,
+            Err(_) => StatusCode::GATEWAY_TIMEOUT.into_response(),
+        },
+        None => next.run(request).await,
+    }
+}
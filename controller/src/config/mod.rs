@@ -0,0 +1,607 @@
+// Módulo de configuração: arquivo TOML sobrescrito por variáveis de ambiente e flags de linha de comando
+use anyhow::{anyhow, bail, Context};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::auth::Role;
+
+// Menor intervalo aceito para o sampler; abaixo disso a própria coleta domina o tempo de CPU
+const MIN_SAMPLER_INTERVAL_MS: u64 = 100;
+
+// Caminhos PEM; client_ca habilita mTLS exigindo certificado de cliente assinado por essa CA
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    pub cert: PathBuf,
+    pub key: PathBuf,
+    pub client_ca: Option<PathBuf>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub listen: Vec<String>,
+    pub backtrace: bool,
+    pub tls: Option<TlsConfig>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        // Só loopback por padrão: sem auth configurada o acesso anônimo é admin
+        ServerConfig { listen: vec!["127.0.0.1:3001".to_string()], backtrace: false, tls: None }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct SamplerConfig {
+    pub interval_ms: u64,
+    pub retention: usize,
+    // /readyz falha se o último tick tiver mais que esse número de intervalos
+    pub ready_max_intervals: u32,
+}
+
+impl Default for SamplerConfig {
+    fn default() -> Self {
+        SamplerConfig {
+            interval_ms: crate::sampler::DEFAULT_INTERVAL.as_millis() as u64,
+            retention: crate::sampler::DEFAULT_RETENTION,
+            ready_max_intervals: 3,
+        }
+    }
+}
+
+impl SamplerConfig {
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms)
+    }
+}
+
+// Grupos de endpoints que podem ser desligados; o sampler também deixa de coletar o que estiver desligado
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(default, deny_unknown_fields)]
+pub struct SubsystemsConfig {
+    pub processes: bool,
+    pub system: bool,
+    pub cpu: bool,
+    pub sensors: bool,
+    pub filesystem: bool,
+    pub storage: bool,
+    pub network: bool,
+}
+
+impl Default for SubsystemsConfig {
+    fn default() -> Self {
+        SubsystemsConfig { processes: true, system: true, cpu: true, sensors: true, filesystem: true, storage: true, network: true }
+    }
+}
+
+impl SubsystemsConfig {
+    pub const NAMES: &'static [&'static str] = &["processes", "system", "cpu", "sensors", "filesystem", "storage", "network"];
+
+    fn flag(&mut self, name: &str) -> Option<&mut bool> {
+        match name {
+            "processes" => Some(&mut self.processes),
+            "system" => Some(&mut self.system),
+            "cpu" => Some(&mut self.cpu),
+            "sensors" => Some(&mut self.sensors),
+            "filesystem" => Some(&mut self.filesystem),
+            "storage" => Some(&mut self.storage),
+            "network" => Some(&mut self.network),
+            _ => None,
+        }
+    }
+}
+
+// Token de API: apenas o SHA-256 (hex) fica no arquivo; gere com `controller --hash-token <token>`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct TokenConfig {
+    pub name: String,
+    pub sha256: String,
+    pub role: Role,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    pub enabled: bool,
+    pub tokens: Vec<TokenConfig>,
+}
+
+// Redação de segredos em ambiente e linha de comando dos processos; patterns são regexes adicionais
+// (com um grupo nomeado "secret", apenas ele é mascarado)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct RedactionConfig {
+    pub enabled: bool,
+    pub builtin: bool,
+    pub patterns: Vec<String>,
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        RedactionConfig { enabled: true, builtin: true, patterns: Vec::new() }
+    }
+}
+
+// Log de auditoria em JSON lines; ao passar de max_bytes o arquivo é rotacionado mantendo max_files antigos
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuditConfig {
+    pub enabled: bool,
+    pub path: PathBuf,
+    pub max_bytes: u64,
+    pub max_files: usize,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        AuditConfig { enabled: false, path: PathBuf::from("audit.jsonl"), max_bytes: 10 * 1024 * 1024, max_files: 5 }
+    }
+}
+
+// Diretórios cujos arquivos /filesystem/tail pode ler; caminhos fora deles, inclusive via symlink, são recusados
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct FilesystemConfig {
+    pub tail_roots: Vec<PathBuf>,
+}
+
+impl Default for FilesystemConfig {
+    fn default() -> Self {
+        let root = if cfg!(windows) { r"C:\Windows\Logs" } else { "/var/log" };
+        FilesystemConfig { tail_roots: vec![PathBuf::from(root)] }
+    }
+}
+
+// Interface web: dir aponta para o build do view/ (view/dist); sem dir usa o build embutido (feature embed-ui)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct UiConfig {
+    pub enabled: bool,
+    pub dir: Option<PathBuf>,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        UiConfig { enabled: true, dir: None }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+// Logs estruturados; level aceita diretivas do tracing ("info", "warn,controller::process=debug")
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    pub level: String,
+    pub format: LogFormat,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        LogConfig { level: "info".to_string(), format: LogFormat::Text }
+    }
+}
+
+// Data (AAAA-MM-DD) anunciada no cabeçalho Sunset das rotas /api/v1 e /api sem versão
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiConfig {
+    pub v1_deprecated: String,
+    pub v1_sunset: String,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        ApiConfig { v1_deprecated: "2026-10-18".to_string(), v1_sunset: "2027-04-30".to_string() }
+    }
+}
+
+// Limites por rota, identificada pelo padrão registrado no Router (ex.: "/api/processes/{pid}/handles")
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct EndpointLimit {
+    pub timeout_ms: Option<u64>,
+    pub max_concurrent: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub sampler: SamplerConfig,
+    pub subsystems: SubsystemsConfig,
+    pub auth: AuthConfig,
+    pub redaction: RedactionConfig,
+    pub audit: AuditConfig,
+    pub filesystem: FilesystemConfig,
+    pub ui: UiConfig,
+    pub api: ApiConfig,
+    pub log: LogConfig,
+    pub limits: BTreeMap<String, EndpointLimit>,
+}
+
+// Flags de linha de comando; cada uma também pode vir de uma variável de ambiente (a flag tem precedência)
+#[derive(Parser, Debug)]
+#[command(name = "controller", version, about = "API de métricas do OS Dashboard")]
+pub struct Cli {
+    /// Arquivo de configuração TOML
+    #[arg(long, env = "OS_DASHBOARD_CONFIG")]
+    pub config: Option<PathBuf>,
+    /// Endereço de escuta (pode ser repetido; substitui server.listen)
+    #[arg(long, env = "OS_DASHBOARD_LISTEN", value_delimiter = ',')]
+    pub listen: Vec<String>,
+    /// Intervalo de coleta do sampler em milissegundos
+    #[arg(long, env = "OS_DASHBOARD_SAMPLER_INTERVAL_MS")]
+    pub sampler_interval_ms: Option<u64>,
+    /// Quantidade de amostras mantidas no histórico
+    #[arg(long, env = "OS_DASHBOARD_HISTORY_RETENTION")]
+    pub history_retention: Option<usize>,
+    /// Subsistema a desligar (pode ser repetido)
+    #[arg(long, env = "OS_DASHBOARD_DISABLE", value_delimiter = ',')]
+    pub disable: Vec<String>,
+    /// Certificado TLS (PEM); exige --tls-key
+    #[arg(long, env = "OS_DASHBOARD_TLS_CERT")]
+    pub tls_cert: Option<PathBuf>,
+    /// Chave privada TLS (PEM)
+    #[arg(long, env = "OS_DASHBOARD_TLS_KEY")]
+    pub tls_key: Option<PathBuf>,
+    /// CA para validar certificados de cliente (habilita mTLS)
+    #[arg(long, env = "OS_DASHBOARD_TLS_CLIENT_CA")]
+    pub tls_client_ca: Option<PathBuf>,
+    /// Arquivo do log de auditoria (habilita a auditoria)
+    #[arg(long, env = "OS_DASHBOARD_AUDIT_LOG")]
+    pub audit_log: Option<PathBuf>,
+    /// Diretório com o build do view/ a ser servido
+    #[arg(long, env = "OS_DASHBOARD_UI_DIR")]
+    pub ui_dir: Option<PathBuf>,
+    /// Nível ou diretivas de log (ex.: debug, "info,controller::process=debug")
+    #[arg(long, env = "OS_DASHBOARD_LOG_LEVEL")]
+    pub log_level: Option<String>,
+    /// Formato dos logs
+    #[arg(long, env = "OS_DASHBOARD_LOG_FORMAT")]
+    pub log_format: Option<LogFormat>,
+    /// Define RUST_BACKTRACE=1
+    #[arg(long, env = "OS_DASHBOARD_BACKTRACE")]
+    pub backtrace: bool,
+    /// Imprime a configuração efetiva em TOML e encerra
+    #[arg(long)]
+    pub print_config: bool,
+    /// Imprime a especificação OpenAPI em JSON e encerra
+    #[arg(long)]
+    pub print_openapi: bool,
+    /// Compara a especificação OpenAPI com um snapshot em JSON e encerra com erro se divergirem
+    #[arg(long, value_name = "ARQUIVO")]
+    pub check_openapi: Option<PathBuf>,
+    /// Imprime o SHA-256 de um token para uso em auth.tokens e encerra
+    #[arg(long, value_name = "TOKEN")]
+    pub hash_token: Option<String>,
+}
+
+impl Config {
+    pub fn from_file(path: &Path) -> Result<Config, anyhow::Error> {
+        let content = std::fs::read_to_string(path).with_context(|| format!("não foi possível ler {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("configuração inválida em {}", path.display()))
+    }
+
+    // Aplica as sobrescritas vindas de ambiente/CLI sobre o arquivo
+    pub fn apply_cli(&mut self, cli: &Cli) -> Result<(), anyhow::Error> {
+        if !cli.listen.is_empty() {
+            self.server.listen = cli.listen.clone();
+        }
+        if let Some(interval_ms) = cli.sampler_interval_ms {
+            self.sampler.interval_ms = interval_ms;
+        }
+        if let Some(retention) = cli.history_retention {
+            self.sampler.retention = retention;
+        }
+        if cli.backtrace {
+            self.server.backtrace = true;
+        }
+        if let Some(dir) = &cli.ui_dir {
+            self.ui.dir = Some(dir.clone());
+        }
+        if let Some(level) = &cli.log_level {
+            self.log.level = level.clone();
+        }
+        if let Some(format) = cli.log_format {
+            self.log.format = format;
+        }
+        if let Some(path) = &cli.audit_log {
+            self.audit.enabled = true;
+            self.audit.path = path.clone();
+        }
+        match (&mut self.server.tls, &cli.tls_cert, &cli.tls_key) {
+            (Some(tls), cert, key) => {
+                if let Some(cert) = cert {
+                    tls.cert = cert.clone();
+                }
+                if let Some(key) = key {
+                    tls.key = key.clone();
+                }
+            }
+            (None, Some(cert), Some(key)) => {
+                self.server.tls = Some(TlsConfig { cert: cert.clone(), key: key.clone(), client_ca: None });
+            }
+            (None, None, None) => {}
+            (None, _, _) => bail!("--tls-cert e --tls-key devem ser informados juntos"),
+        }
+        if let Some(ca) = &cli.tls_client_ca {
+            match &mut self.server.tls {
+                Some(tls) => tls.client_ca = Some(ca.clone()),
+                None => bail!("--tls-client-ca exige --tls-cert e --tls-key"),
+            }
+        }
+        for name in &cli.disable {
+            let flag = self.subsystems.flag(name.trim()).ok_or_else(|| {
+                anyhow!("--disable: subsistema desconhecido '{}' (válidos: {})", name, SubsystemsConfig::NAMES.join(", "))
+            })?;
+            *flag = false;
+        }
+        Ok(())
+    }
+
+    pub fn listen_addrs(&self) -> Result<Vec<SocketAddr>, anyhow::Error> {
+        self.server
+            .listen
+            .iter()
+            .enumerate()
+            .map(|(i, addr)| addr.parse().map_err(|_| anyhow!("server.listen[{}]: endereço inválido '{}' (esperado IP:porta)", i, addr)))
+            .collect()
+    }
+
+    // Verifica a configuração antes de subir o servidor
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.server.listen.is_empty() {
+            bail!("server.listen: informe ao menos um endereço");
+        }
+        let addrs = self.listen_addrs()?;
+        if !self.auth.enabled {
+            if let Some((i, addr)) = addrs.iter().enumerate().find(|(_, a)| !a.ip().is_loopback()) {
+                bail!(
+                    "server.listen[{}]: {} aceita conexões de outras máquinas e, sem autenticação, todas seriam admin; habilite [auth] ou escute em 127.0.0.1/[::1]",
+                    i,
+                    addr
+                );
+            }
+        }
+        if self.sampler.interval_ms < MIN_SAMPLER_INTERVAL_MS {
+            bail!("sampler.interval_ms: {} é menor que o mínimo de {} ms", self.sampler.interval_ms, MIN_SAMPLER_INTERVAL_MS);
+        }
+        crate::logging::parse_filter(&self.log.level).context("log.level")?;
+        if self.sampler.ready_max_intervals == 0 {
+            bail!("sampler.ready_max_intervals deve ser pelo menos 1");
+        }
+        if self.sampler.retention == 0 {
+            bail!("sampler.retention: deve ser maior que zero");
+        }
+        if self.auth.enabled && self.auth.tokens.is_empty() {
+            bail!("auth.tokens: auth.enabled = true exige ao menos um token");
+        }
+        let mut seen = std::collections::HashSet::new();
+        for (i, token) in self.auth.tokens.iter().enumerate() {
+            if token.name.trim().is_empty() {
+                bail!("auth.tokens[{}].name: não pode ser vazio", i);
+            }
+            if token.sha256.len() != 64 || !token.sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+                bail!("auth.tokens[{}].sha256: esperado SHA-256 em hexadecimal (64 caracteres), não o token em si", i);
+            }
+            if !seen.insert(token.sha256.to_ascii_lowercase()) {
+                bail!("auth.tokens[{}]: hash repetido (token '{}')", i, token.name);
+            }
+        }
+        crate::process::redact::Redactor::from_config(&self.redaction)?;
+        crate::api::Deprecation::new(&self.api.v1_deprecated, &self.api.v1_sunset)?;
+        for (i, root) in self.filesystem.tail_roots.iter().enumerate() {
+            if !root.is_absolute() {
+                bail!("filesystem.tail_roots[{}]: '{}' não é um caminho absoluto", i, root.display());
+            }
+        }
+        if let Some(dir) = self.ui.dir.as_ref().filter(|_| self.ui.enabled) {
+            if !dir.join("index.html").is_file() {
+                bail!("ui.dir: {} não contém index.html (rode `npm run build` em view/)", dir.display());
+            }
+        }
+        if self.audit.enabled && self.audit.max_bytes < 1024 {
+            bail!("audit.max_bytes: {} é pequeno demais (mínimo 1024)", self.audit.max_bytes);
+        }
+        // Com zero arquivos a rotação apagaria o log inteiro a cada max_bytes
+        if self.audit.enabled && self.audit.max_files < 1 {
+            bail!("audit.max_files: mantenha ao menos 1 arquivo rotacionado");
+        }
+        let routes = crate::api_routes();
+        for (route, limit) in &self.limits {
+            if !route.starts_with("/api/") || route.starts_with("/api/v1/") || route.starts_with("/api/v2/") {
+                bail!("limits.\"{}\": use a rota sem versão começando com /api/ (vale para /api/v1 e /api/v2)", route);
+            }
+            if !routes.contains(route) {
+                bail!("limits.\"{}\": rota desconhecida (use o caminho registrado, como /api/processes/{{pid}})", route);
+            }
+            if limit.timeout_ms == Some(0) {
+                bail!("limits.\"{}\".timeout_ms: deve ser maior que zero", route);
+            }
+            if limit.max_concurrent == Some(0) {
+                bail!("limits.\"{}\".max_concurrent: deve ser maior que zero", route);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_toml(&self) -> Result<String, anyhow::Error> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+}
+
+// Função para montar a configuração efetiva: padrões < arquivo < ambiente < flags
+pub fn load(cli: &Cli) -> Result<Config, anyhow::Error> {
+    let mut config = match &cli.config {
+        Some(path) => Config::from_file(path)?,
+        None => Config::default(),
+    };
+    config.apply_cli(cli)?;
+    config.validate()?;
+    Ok(config)
+}
+
+pub mod limits;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_limit(route: &str) -> Config {
+        let mut config = Config::default();
+        config.limits.insert(route.to_string(), EndpointLimit { timeout_ms: Some(1000), max_concurrent: None });
+        config
+    }
+
+    #[test]
+    fn limits_keys_must_be_known_routes() {
+        assert!(with_limit("/api/processes").validate().is_ok());
+        assert!(with_limit("/api/processes/{pid}/handles").validate().is_ok());
+        assert!(with_limit("/api/proceses").validate().is_err());
+        assert!(with_limit("/api/v2/processes").validate().is_err());
+        assert!(with_limit("/healthz").validate().is_err());
+    }
+
+    #[test]
+    fn anonymous_access_only_on_loopback() {
+        let mut config = Config::default();
+        assert!(config.validate().is_ok());
+        config.server.listen = vec!["127.0.0.1:3001".to_string(), "[::1]:3001".to_string()];
+        assert!(config.validate().is_ok());
+        for addr in ["[::]:3001", "0.0.0.0:3001", "192.168.0.10:3001"] {
+            config.server.listen = vec!["127.0.0.1:3001".to_string(), addr.to_string()];
+            assert!(config.validate().unwrap_err().to_string().starts_with("server.listen[1]"), "{}", addr);
+        }
+        // Com tokens configurados qualquer endereço vale
+        config.auth.enabled = true;
+        config.auth.tokens = vec![TokenConfig { name: "ops".to_string(), sha256: "ab".repeat(32), role: crate::auth::Role::Admin }];
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn audit_keeps_at_least_one_rotated_file() {
+        let mut config = Config::default();
+        config.audit.max_files = 0;
+        // Sem auditoria habilitada o valor não é usado
+        assert!(config.validate().is_ok());
+        config.audit.enabled = true;
+        assert!(config.validate().unwrap_err().to_string().contains("audit.max_files"));
+        config.audit.max_files = 1;
+        assert!(config.validate().is_ok());
+    }
+
+    // O erro aponta a chave problemática logo no início da mensagem
+    fn rejected(config: &Config, key: &str) {
+        let err = config.validate().expect_err(key).to_string();
+        assert!(err.starts_with(key), "esperado erro em {}, veio: {}", key, err);
+    }
+
+    #[test]
+    fn sampler_values_must_be_positive() {
+        for interval_ms in [0, 1, MIN_SAMPLER_INTERVAL_MS - 1] {
+            let mut config = Config::default();
+            config.sampler.interval_ms = interval_ms;
+            rejected(&config, "sampler.interval_ms");
+        }
+        let mut config = Config::default();
+        config.sampler.interval_ms = MIN_SAMPLER_INTERVAL_MS;
+        assert!(config.validate().is_ok());
+        config.sampler.retention = 0;
+        rejected(&config, "sampler.retention");
+        config.sampler.retention = 1;
+        config.sampler.ready_max_intervals = 0;
+        rejected(&config, "sampler.ready_max_intervals");
+    }
+
+    #[test]
+    fn file_and_flags_are_validated() {
+        let dir = crate::testutil::TempDir::with_files("config", &[("zero.toml", "[sampler]\ninterval_ms = 0\n"), ("ok.toml", "[sampler]\nretention = 10\n")]);
+        let cli = |args: &[&str]| Cli::try_parse_from(std::iter::once("controller").chain(args.iter().copied())).unwrap();
+        let zero = dir.path().join("zero.toml");
+        let ok = dir.path().join("ok.toml");
+        assert!(load(&cli(&["--config", zero.to_str().unwrap()])).unwrap_err().to_string().starts_with("sampler.interval_ms"));
+        // A flag sobrescreve o arquivo antes da validação
+        let config = load(&cli(&["--config", zero.to_str().unwrap(), "--sampler-interval-ms", "500"])).unwrap();
+        assert_eq!((config.sampler.interval_ms, config.sampler.retention), (500, crate::sampler::DEFAULT_RETENTION));
+        assert!(load(&cli(&["--config", ok.to_str().unwrap(), "--history-retention", "0"])).unwrap_err().to_string().starts_with("sampler.retention"));
+        assert_eq!(load(&cli(&["--config", ok.to_str().unwrap()])).unwrap().sampler.retention, 10);
+        // Chave desconhecida no arquivo é erro, não ignorada
+        dir.write("typo.toml", "[sampler]\ninterval = 500\n");
+        assert!(load(&cli(&["--config", dir.path().join("typo.toml").to_str().unwrap()])).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_listen_addresses() {
+        let mut config = Config::default();
+        config.server.listen.clear();
+        rejected(&config, "server.listen");
+        config.server.listen = vec!["127.0.0.1:3001".to_string(), "localhost:3001".to_string()];
+        rejected(&config, "server.listen[1]");
+    }
+
+    #[test]
+    fn auth_tokens_are_checked() {
+        let token = |name: &str, sha256: String| TokenConfig { name: name.to_string(), sha256, role: Role::Viewer };
+        let mut config = Config::default();
+        config.auth.enabled = true;
+        rejected(&config, "auth.tokens");
+        config.auth.tokens = vec![token(" ", "ab".repeat(32))];
+        rejected(&config, "auth.tokens[0].name");
+        // O próprio token no lugar do hash
+        config.auth.tokens = vec![token("grafana", "s3cr3t-token".to_string())];
+        rejected(&config, "auth.tokens[0].sha256");
+        config.auth.tokens = vec![token("grafana", "zz".repeat(32))];
+        rejected(&config, "auth.tokens[0].sha256");
+        config.auth.tokens = vec![token("grafana", "ab".repeat(32)), token("ops", "AB".repeat(32))];
+        rejected(&config, "auth.tokens[1]");
+        config.auth.tokens.pop();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_other_invalid_sections() {
+        let mut config = Config::default();
+        config.log.level = "info,controller=loud".to_string();
+        rejected(&config, "log.level");
+
+        let mut config = Config::default();
+        config.redaction.patterns = vec!["(".to_string()];
+        rejected(&config, "redaction.patterns[0]");
+
+        let mut config = Config::default();
+        config.api.v1_sunset = config.api.v1_deprecated.clone();
+        rejected(&config, "api.v1_deprecated");
+
+        let mut config = Config::default();
+        config.audit.enabled = true;
+        config.audit.max_bytes = 100;
+        rejected(&config, "audit.max_bytes");
+
+        for limit in [EndpointLimit { timeout_ms: Some(0), max_concurrent: None }, EndpointLimit { timeout_ms: None, max_concurrent: Some(0) }] {
+            let mut config = Config::default();
+            config.limits.insert("/api/processes".to_string(), limit);
+            rejected(&config, "limits.\"/api/processes\".");
+        }
+    }
+
+    #[test]
+    fn tail_roots_must_be_absolute() {
+        let mut config = Config::default();
+        assert!(config.validate().is_ok());
+        config.filesystem.tail_roots = vec![PathBuf::from("logs")];
+        assert!(config.validate().is_err());
+    }
+}
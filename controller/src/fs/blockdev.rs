@@ -0,0 +1,207 @@
+// Módulo para a topologia de dispositivos de bloco (/sys/block)
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::path::Path;
+use tokio::task;
+use crate::sysfs::{read_attr, read_flag, list_names};
+
+#[derive(Serialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockDeviceKind {
+    Disk,
+    Partition,
+    Lvm,
+    DeviceMapper,
+    Loop,
+    Raid,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct BlockDevice {
+    pub name: String,
+    pub kind: BlockDeviceKind,
+    pub major: Option<u32>,
+    pub minor: Option<u32>,
+    pub size_bytes: u64,
+    pub read_only: bool,
+    pub rotational: Option<bool>,
+    pub removable: Option<bool>,
+    pub model: Option<String>,
+    pub vendor: Option<String>,
+    pub serial: Option<String>,
+    pub scheduler: Option<String>,
+    pub parent: Option<String>,
+    pub holders: Vec<String>,
+    pub slaves: Vec<String>,
+    pub dm_name: Option<String>,
+    pub raid_level: Option<String>,
+    pub loop_backing_file: Option<String>,
+}
+
+// O escalonador ativo aparece entre colchetes: "mq-deadline kyber [bfq] none"
+fn active_scheduler(raw: &str) -> Option<String> {
+    match (raw.find('['), raw.find(']')) {
+        (Some(start), Some(end)) if end > start => Some(raw[start + 1..end].to_string()),
+        _ => raw.split_whitespace().next().map(String::from),
+    }
+}
+
+fn read_device(dir: &Path, name: &str, parent: Option<&BlockDevice>) -> BlockDevice {
+    let (major, minor) = match read_attr(&dir.join("dev")).as_deref().and_then(|d| d.split_once(':')) {
+        Some((ma, mi)) => (ma.parse().ok(), mi.parse().ok()),
+        None => (None, None),
+    };
+    let dm_uuid = read_attr(&dir.join("dm/uuid"));
+    let kind = if parent.is_some() {
+        BlockDeviceKind::Partition
+    } else if name.starts_with("dm-") {
+        if dm_uuid.as_deref().is_some_and(|u| u.starts_with("LVM-")) { BlockDeviceKind::Lvm } else { BlockDeviceKind::DeviceMapper }
+    } else if name.starts_with("loop") {
+        BlockDeviceKind::Loop
+    } else if name.starts_with("md") {
+        BlockDeviceKind::Raid
+    } else {
+        BlockDeviceKind::Disk
+    };
+    let device_dir = dir.join("device");
+    // Partições herdam as características físicas do disco pai
+    let inherited = |value: Option<String>, from_parent: fn(&BlockDevice) -> &Option<String>| {
+        value.or_else(|| parent.and_then(|p| from_parent(p).clone()))
+    };
+    BlockDevice {
+        name: name.to_string(),
+        kind,
+        major,
+        minor,
+        size_bytes: read_attr(&dir.join("size")).and_then(|s| s.parse::<u64>().ok()).unwrap_or(0) * 512,
+        read_only: read_flag(&dir.join("ro")).unwrap_or(false),
+        rotational: read_flag(&dir.join("queue/rotational")).or_else(|| parent.and_then(|p| p.rotational)),
+        removable: read_flag(&dir.join("removable")).or_else(|| parent.and_then(|p| p.removable)),
+        model: inherited(read_attr(&device_dir.join("model")), |p| &p.model),
+        vendor: inherited(read_attr(&device_dir.join("vendor")), |p| &p.vendor),
+        serial: inherited(read_attr(&device_dir.join("serial")).or_else(|| read_attr(&device_dir.join("wwid"))), |p| &p.serial),
+        scheduler: read_attr(&dir.join("queue/scheduler")).and_then(|s| active_scheduler(&s)).or_else(|| parent.and_then(|p| p.scheduler.clone())),
+        parent: parent.map(|p| p.name.clone()),
+        holders: list_names(&dir.join("holders")),
+        slaves: list_names(&dir.join("slaves")),
+        dm_name: read_attr(&dir.join("dm/name")),
+        raid_level: read_attr(&dir.join("md/level")),
+        loop_backing_file: read_attr(&dir.join("loop/backing_file")),
+    }
+}
+
+// Função para listar discos e partições a partir de uma raiz sysfs (normalmente "/sys")
+pub fn list_block_devices_at(sys_root: &Path) -> std::io::Result<Vec<BlockDevice>> {
+    let block_dir = sys_root.join("block");
+    let mut names: Vec<String> = std::fs::read_dir(&block_dir)?.flatten().map(|e| e.file_name().to_string_lossy().to_string()).collect();
+    names.sort();
+    let mut result = Vec::new();
+    for name in names {
+        let dir = block_dir.join(&name);
+        let disk = read_device(&dir, &name, None);
+        // Partições são subdiretórios do disco que contêm o arquivo "partition"
+        let mut partitions: Vec<String> = list_names(&dir).into_iter().filter(|p| dir.join(p).join("partition").exists()).collect();
+        partitions.sort();
+        let parts: Vec<BlockDevice> = partitions.iter().map(|p| read_device(&dir.join(p), p, Some(&disk))).collect();
+        result.push(disk);
+        result.extend(parts);
+    }
+    Ok(result)
+}
+
+// Função para listar dispositivos de bloco do sistema
+pub async fn list_block_devices() -> Result<Vec<BlockDevice>, anyhow::Error> {
+    let devices = task::spawn_blocking(|| list_block_devices_at(Path::new("/sys"))).await??;
+    Ok(devices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn reads_disks_partitions_and_holders() {
+        // Em /sys as entradas de holders/slaves são symlinks; só o nome importa
+        let root = TempDir::with_files(
+            "blockdev",
+            &[
+                ("block/sda/dev", "8:0\n"),
+                ("block/sda/size", "1953525168\n"),
+                ("block/sda/ro", "0\n"),
+                ("block/sda/removable", "0\n"),
+                ("block/sda/queue/rotational", "1\n"),
+                ("block/sda/queue/scheduler", "mq-deadline kyber [bfq] none\n"),
+                ("block/sda/device/model", "WDC WD10EZEX-08W\n"),
+                ("block/sda/device/vendor", "ATA     \n"),
+                ("block/sda/device/wwid", "naa.50014ee2b5c8a1b2\n"),
+                ("block/sda/sda1/partition", "1\n"),
+                ("block/sda/sda1/dev", "8:1\n"),
+                ("block/sda/sda1/size", "1048576\n"),
+                ("block/sda/sda2/partition", "2\n"),
+                ("block/sda/sda2/dev", "8:2\n"),
+                ("block/sda/sda2/size", "1952474769\n"),
+                ("block/sda/sda2/holders/dm-0", ""),
+                // Subdiretório sem "partition" não é partição
+                ("block/sda/queue/nr_requests", "64\n"),
+                ("block/dm-0/dev", "253:0\n"),
+                ("block/dm-0/size", "209715200\n"),
+                ("block/dm-0/dm/name", "vg0-root\n"),
+                ("block/dm-0/dm/uuid", "LVM-abc123\n"),
+                ("block/dm-0/slaves/sda2", ""),
+                ("block/sdb/dev", "8:16\n"),
+                ("block/sdb/size", "60063744\n"),
+                ("block/sdb/ro", "1\n"),
+                ("block/sdb/removable", "1\n"),
+                ("block/sdb/queue/rotational", "0\n"),
+                ("block/loop0/dev", "7:0\n"),
+                ("block/loop0/loop/backing_file", "/var/lib/snapd/snaps/core_1.snap\n"),
+            ],
+        );
+        let devices = list_block_devices_at(root.path()).unwrap();
+        let names: Vec<(&str, BlockDeviceKind)> = devices.iter().map(|d| (d.name.as_str(), d.kind)).collect();
+        assert_eq!(
+            names,
+            vec![
+                ("dm-0", BlockDeviceKind::Lvm),
+                ("loop0", BlockDeviceKind::Loop),
+                ("sda", BlockDeviceKind::Disk),
+                ("sda1", BlockDeviceKind::Partition),
+                ("sda2", BlockDeviceKind::Partition),
+                ("sdb", BlockDeviceKind::Disk),
+            ]
+        );
+        let device = |name: &str| devices.iter().find(|d| d.name == name).unwrap();
+
+        let sda = device("sda");
+        assert_eq!((sda.major, sda.minor, sda.size_bytes), (Some(8), Some(0), 1953525168 * 512));
+        assert_eq!((sda.rotational, sda.removable, sda.read_only), (Some(true), Some(false), false));
+        assert_eq!((sda.scheduler.as_deref(), sda.vendor.as_deref()), (Some("bfq"), Some("ATA")));
+        // Sem serial o wwid identifica o disco
+        assert_eq!(sda.serial.as_deref(), Some("naa.50014ee2b5c8a1b2"));
+
+        // Partições herdam rotational, removable, modelo e escalonador do disco
+        let sda2 = device("sda2");
+        assert_eq!((sda2.parent.as_deref(), sda2.minor), (Some("sda"), Some(2)));
+        assert_eq!((sda2.rotational, sda2.removable), (Some(true), Some(false)));
+        assert_eq!((sda2.model.as_deref(), sda2.scheduler.as_deref()), (Some("WDC WD10EZEX-08W"), Some("bfq")));
+        assert_eq!(sda2.holders, vec!["dm-0"]);
+        assert!(device("sda1").holders.is_empty());
+
+        let dm = device("dm-0");
+        assert_eq!((dm.dm_name.as_deref(), dm.slaves.clone()), (Some("vg0-root"), vec!["sda2".to_string()]));
+        assert_eq!(dm.rotational, None);
+
+        let sdb = device("sdb");
+        assert_eq!((sdb.rotational, sdb.removable, sdb.read_only), (Some(false), Some(true), true));
+        assert_eq!(device("loop0").loop_backing_file.as_deref(), Some("/var/lib/snapd/snaps/core_1.snap"));
+    }
+
+    #[test]
+    fn picks_active_scheduler() {
+        assert_eq!(active_scheduler("[none] mq-deadline").as_deref(), Some("none"));
+        // Kernels antigos com um único escalonador não usam colchetes
+        assert_eq!(active_scheduler("noop").as_deref(), Some("noop"));
+        assert_eq!(active_scheduler(""), None);
+    }
+}
@@ -0,0 +1,107 @@
+// FFI para DeviceIoControl e IOCTL_DISK_PERFORMANCE (contadores de E/S por volume no Windows)
+use std::ffi::OsStr;
+use std::os::windows::ffi::OsStrExt;
+use std::ptr::null_mut;
+use std::mem::size_of;
+use std::os::raw::c_void;
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DISK_PERFORMANCE {
+    pub BytesRead: i64,
+    pub BytesWritten: i64,
+    pub ReadTime: i64,
+    pub WriteTime: i64,
+    pub IdleTime: i64,
+    pub ReadCount: u32,
+    pub WriteCount: u32,
+    pub QueueDepth: u32,
+    pub SplitCount: u32,
+    pub QueryTime: i64,
+    pub StorageDeviceNumber: u32,
+    pub StorageManagerName: [u16; 8],
+}
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn CreateFileW(
+        lpFileName: *const u16,
+        dwDesiredAccess: u32,
+        dwShareMode: u32,
+        lpSecurityAttributes: *mut c_void,
+        dwCreationDisposition: u32,
+        dwFlagsAndAttributes: u32,
+        hTemplateFile: *mut c_void,
+    ) -> *mut c_void;
+    fn DeviceIoControl(
+        hDevice: *mut c_void,
+        dwIoControlCode: u32,
+        lpInBuffer: *mut c_void,
+        nInBufferSize: u32,
+        lpOutBuffer: *mut c_void,
+        nOutBufferSize: u32,
+        lpBytesReturned: *mut u32,
+        lpOverlapped: *mut c_void,
+    ) -> i32;
+    fn CloseHandle(hObject: *mut c_void) -> i32;
+}
+
+const FILE_SHARE_READ: u32 = 0x00000001;
+const FILE_SHARE_WRITE: u32 = 0x00000002;
+const OPEN_EXISTING: u32 = 3;
+const IOCTL_DISK_PERFORMANCE: u32 = 0x70020;
+const INVALID_HANDLE_VALUE: *mut c_void = -1isize as *mut c_void;
+
+// Contadores acumulados do volume (ex.: "C"); None quando o IOCTL falha (contadores desligados ou sem acesso)
+pub fn query(drive_letter: &str) -> Option<DISK_PERFORMANCE> {
+    let device = format!("\\\\.\\{}:", drive_letter.trim_end_matches(':'));
+    let wdevice: Vec<u16> = OsStr::new(&device).encode_wide().chain(Some(0)).collect();
+    unsafe {
+        // Acesso 0 basta para IOCTLs de consulta; GENERIC_READ no volume exige administrador e fazia a chamada falhar
+        let handle = CreateFileW(
+            wdevice.as_ptr(),
+            0,
+            FILE_SHARE_READ | FILE_SHARE_WRITE,
+            null_mut(),
+            OPEN_EXISTING,
+            0,
+            null_mut(),
+        );
+        // CreateFileW sinaliza falha com INVALID_HANDLE_VALUE, não com null
+        if handle.is_null() || handle == INVALID_HANDLE_VALUE {
+            return None;
+        }
+        let mut perf = DISK_PERFORMANCE::default();
+        let mut bytes_returned = 0u32;
+        let ok = DeviceIoControl(
+            handle,
+            IOCTL_DISK_PERFORMANCE,
+            null_mut(),
+            0,
+            &mut perf as *mut _ as *mut c_void,
+            size_of::<DISK_PERFORMANCE>() as u32,
+            &mut bytes_returned,
+            null_mut(),
+        );
+        CloseHandle(handle);
+        if ok != 0 {
+            Some(perf)
+        } else {
+            None
+        }
+    }
+}
+
+// Letras dos volumes fixos (GetDriveTypeW == DRIVE_FIXED); removíveis, rede e CD ficam de fora
+pub fn fixed_volumes() -> Vec<char> {
+    let drives = unsafe { super::GetLogicalDrives() };
+    (0..26u8)
+        .filter(|i| drives & (1 << i) != 0)
+        .map(|i| (b'A' + i) as char)
+        .filter(|letter| {
+            let root: Vec<u16> = OsStr::new(&format!("{}:\\", letter)).encode_wide().chain(Some(0)).collect();
+            unsafe { super::GetDriveTypeW(root.as_ptr()) == 3 }
+        })
+        .collect()
+}
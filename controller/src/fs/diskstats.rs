@@ -0,0 +1,290 @@
+// Módulo para taxas de E/S por dispositivo de bloco a partir de /proc/diskstats (no Windows, por volume via IOCTL_DISK_PERFORMANCE)
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::collections::HashMap;
+#[cfg(target_os = "linux")]
+use std::path::Path;
+use std::time::Instant;
+
+// O kernel sempre conta setores de 512 bytes em /proc/diskstats
+#[cfg(any(target_os = "linux", test))]
+const SECTOR_SIZE: u64 = 512;
+
+// Contadores acumulados de um dispositivo; as taxas saem da diferença entre duas leituras
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiskStat {
+    pub major: u32,
+    pub minor: u32,
+    pub name: String,
+    pub reads: u64,
+    pub bytes_read: u64,
+    pub read_ms: u64,
+    pub writes: u64,
+    pub bytes_written: u64,
+    pub write_ms: u64,
+    pub in_flight: u64,
+    pub io_ms: u64,
+    pub weighted_io_ms: u64,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct DiskIoRate {
+    pub name: String,
+    pub major: u32,
+    pub minor: u32,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    pub await_ms: f64,
+    pub utilization_percent: f64,
+    pub queue_depth: f64,
+    pub in_flight: u64,
+    pub mount_points: Vec<String>,
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn parse_line(line: &str) -> Option<DiskStat> {
+    let f: Vec<&str> = line.split_whitespace().collect();
+    if f.len() < 14 {
+        return None;
+    }
+    let n = |i: usize| f[i].parse::<u64>().ok();
+    Some(DiskStat {
+        major: f[0].parse().ok()?,
+        minor: f[1].parse().ok()?,
+        name: f[2].to_string(),
+        reads: n(3)?,
+        bytes_read: n(5)? * SECTOR_SIZE,
+        read_ms: n(6)?,
+        writes: n(7)?,
+        bytes_written: n(9)? * SECTOR_SIZE,
+        write_ms: n(10)?,
+        in_flight: n(11)?,
+        io_ms: n(12)?,
+        weighted_io_ms: n(13)?,
+    })
+}
+
+// Função para interpretar o conteúdo de /proc/diskstats
+#[cfg(any(target_os = "linux", test))]
+pub fn parse_diskstats(content: &str) -> Vec<DiskStat> {
+    content.lines().filter_map(parse_line).collect()
+}
+
+// Função para calcular as taxas entre duas leituras separadas por `elapsed_secs`
+pub fn compute_rate(prev: &DiskStat, cur: &DiskStat, elapsed_secs: f64) -> DiskIoRate {
+    let d = |a: u64, b: u64| b.saturating_sub(a) as f64;
+    let reads = d(prev.reads, cur.reads);
+    let writes = d(prev.writes, cur.writes);
+    let ios = reads + writes;
+    let elapsed = if elapsed_secs > 0.0 { elapsed_secs } else { 1.0 };
+    DiskIoRate {
+        name: cur.name.clone(),
+        major: cur.major,
+        minor: cur.minor,
+        read_bytes_per_sec: d(prev.bytes_read, cur.bytes_read) / elapsed,
+        write_bytes_per_sec: d(prev.bytes_written, cur.bytes_written) / elapsed,
+        read_iops: reads / elapsed,
+        write_iops: writes / elapsed,
+        await_ms: if ios > 0.0 { (d(prev.read_ms, cur.read_ms) + d(prev.write_ms, cur.write_ms)) / ios } else { 0.0 },
+        utilization_percent: (d(prev.io_ms, cur.io_ms) / (elapsed * 1000.0) * 100.0).min(100.0),
+        queue_depth: d(prev.weighted_io_ms, cur.weighted_io_ms) / (elapsed * 1000.0),
+        in_flight: cur.in_flight,
+        mount_points: Vec::new(),
+    }
+}
+
+// Helper para descobrir os dispositivos que sustentam `name`: o disco pai de uma partição e os
+// "slaves" de volumes device-mapper/md, recursivamente
+#[cfg(target_os = "linux")]
+fn backing_devices(sys_block: &Path, name: &str, out: &mut Vec<String>) {
+    if out.iter().any(|n| n == name) {
+        return;
+    }
+    out.push(name.to_string());
+    let dev_dir = sys_block.join(name);
+    if dev_dir.join("partition").exists() {
+        if let Some(parent) = std::fs::canonicalize(&dev_dir).ok().and_then(|p| p.parent().and_then(|p| p.file_name()).map(|n| n.to_string_lossy().to_string())) {
+            backing_devices(sys_block, &parent, out);
+        }
+    }
+    if let Ok(slaves) = std::fs::read_dir(dev_dir.join("slaves")) {
+        for slave in slaves.flatten() {
+            backing_devices(sys_block, &slave.file_name().to_string_lossy(), out);
+        }
+    }
+}
+
+// Função para mapear cada dispositivo de bloco aos pontos de montagem que ele sustenta
+#[cfg(target_os = "linux")]
+pub fn device_mount_map(sys_root: &Path, mountinfo: &str) -> HashMap<String, Vec<String>> {
+    let sys_block = sys_root.join("class/block");
+    let mut map: HashMap<String, Vec<String>> = HashMap::new();
+    for entry in super::mountinfo::parse_mountinfo(mountinfo) {
+        if entry.major == 0 {
+            continue;
+        }
+        let dev_link = sys_root.join(format!("dev/block/{}:{}", entry.major, entry.minor));
+        let name = match std::fs::canonicalize(&dev_link).ok().and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string())) {
+            Some(n) => n,
+            None => continue,
+        };
+        let mut devices = Vec::new();
+        backing_devices(&sys_block, &name, &mut devices);
+        for dev in devices {
+            let mounts = map.entry(dev).or_default();
+            if !mounts.contains(&entry.mount_point) {
+                mounts.push(entry.mount_point.clone());
+            }
+        }
+    }
+    map
+}
+
+#[cfg(target_os = "linux")]
+fn read_stats() -> std::io::Result<HashMap<String, DiskStat>> {
+    let content = std::fs::read_to_string("/proc/diskstats")?;
+    Ok(parse_diskstats(&content).into_iter().map(|s| (s.name.clone(), s)).collect())
+}
+
+#[cfg(target_os = "linux")]
+fn current_mount_map(_devices: &HashMap<String, DiskStat>) -> HashMap<String, Vec<String>> {
+    std::fs::read_to_string("/proc/self/mountinfo").map(|m| device_mount_map(Path::new("/sys"), &m)).unwrap_or_default()
+}
+
+// No Windows cada "dispositivo" é um volume com letra: tempos em unidades de 100 ns, ocupado = tempo corrido - ocioso.
+// ReadTime + WriteTime soma o tempo de cada requisição, o mesmo que o tempo ponderado do Linux.
+#[cfg(windows)]
+fn from_disk_performance(letter: char, perf: &super::diskperf::DISK_PERFORMANCE) -> DiskStat {
+    let ms = |t: i64| t.max(0) as u64 / 10_000;
+    DiskStat {
+        major: 0,
+        minor: 0,
+        name: format!("{}:", letter),
+        reads: perf.ReadCount as u64,
+        bytes_read: perf.BytesRead.max(0) as u64,
+        read_ms: ms(perf.ReadTime),
+        writes: perf.WriteCount as u64,
+        bytes_written: perf.BytesWritten.max(0) as u64,
+        write_ms: ms(perf.WriteTime),
+        in_flight: perf.QueueDepth as u64,
+        io_ms: ms(perf.QueryTime - perf.IdleTime),
+        weighted_io_ms: ms(perf.ReadTime + perf.WriteTime),
+    }
+}
+
+// Volumes cujo IOCTL falha (contadores de disco desligados ou sem acesso) ficam de fora; sem nenhum, é erro
+#[cfg(windows)]
+fn read_stats() -> std::io::Result<HashMap<String, DiskStat>> {
+    let stats: HashMap<String, DiskStat> = super::diskperf::fixed_volumes()
+        .into_iter()
+        .filter_map(|letter| Some(from_disk_performance(letter, &super::diskperf::query(&letter.to_string())?)))
+        .map(|s| (s.name.clone(), s))
+        .collect();
+    if stats.is_empty() {
+        return Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "IOCTL_DISK_PERFORMANCE indisponível"));
+    }
+    Ok(stats)
+}
+
+// O volume "C:" sustenta apenas a montagem "C:\"
+#[cfg(windows)]
+fn current_mount_map(devices: &HashMap<String, DiskStat>) -> HashMap<String, Vec<String>> {
+    devices.keys().map(|name| (name.clone(), vec![format!("{}\\", name)])).collect()
+}
+
+// Guarda a última leitura dos contadores acumulados; a primeira amostra só serve de base
+#[derive(Default)]
+pub struct DiskIoCollector {
+    previous: Option<(Instant, HashMap<String, DiskStat>)>,
+}
+
+impl DiskIoCollector {
+    // Taxas por dispositivo no intervalo desde a leitura anterior, com os pontos de montagem que cada um sustenta
+    pub fn sample(&mut self) -> std::io::Result<Vec<DiskIoRate>> {
+        let current = read_stats()?;
+        let now = Instant::now();
+        let mut rates = Vec::new();
+        if let Some((prev_at, prev)) = &self.previous {
+            let elapsed = now.duration_since(*prev_at).as_secs_f64();
+            let mounts = current_mount_map(&current);
+            for (name, cur) in &current {
+                if let Some(p) = prev.get(name) {
+                    let mut rate = compute_rate(p, cur, elapsed);
+                    rate.mount_points = mounts.get(name).cloned().unwrap_or_default();
+                    rates.push(rate);
+                }
+            }
+            rates.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        self.previous = Some((now, current));
+        Ok(rates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DISKSTATS: &str = "\
+   8       0 sda 1000 10 16000 500 2000 20 32000 1500 0 1800 2000 0 0 0 0 0 0
+   8       1 sda1 900 5 14000 450 1900 15 30000 1400 0 1700 1850
+ 253       0 dm-0 50 0 800 20 60 0 960 30 1 40 50 0 0 0 0
+   7       0 loop0 short line
+";
+
+    #[test]
+    fn parses_diskstats_fixture() {
+        let stats = parse_diskstats(DISKSTATS);
+        assert_eq!(stats.len(), 3);
+        assert_eq!(
+            stats[0],
+            DiskStat {
+                major: 8,
+                minor: 0,
+                name: "sda".to_string(),
+                reads: 1000,
+                bytes_read: 16000 * 512,
+                read_ms: 500,
+                writes: 2000,
+                bytes_written: 32000 * 512,
+                write_ms: 1500,
+                in_flight: 0,
+                io_ms: 1800,
+                weighted_io_ms: 2000,
+            }
+        );
+        // Kernels antigos têm só os 14 campos clássicos; os de descarte/flush são ignorados
+        assert_eq!(stats[1].name, "sda1");
+        assert_eq!((stats[2].major, stats[2].minor, stats[2].in_flight), (253, 0, 1));
+    }
+
+    #[test]
+    fn computes_rates_between_samples() {
+        let prev = parse_diskstats(DISKSTATS).remove(0);
+        let cur = DiskStat {
+            reads: prev.reads + 100,
+            bytes_read: prev.bytes_read + 2_000_000,
+            read_ms: prev.read_ms + 200,
+            writes: prev.writes + 300,
+            bytes_written: prev.bytes_written + 6_000_000,
+            write_ms: prev.write_ms + 600,
+            io_ms: prev.io_ms + 1000,
+            weighted_io_ms: prev.weighted_io_ms + 3000,
+            in_flight: 2,
+            ..prev.clone()
+        };
+        let rate = compute_rate(&prev, &cur, 2.0);
+        assert_eq!(rate.read_bytes_per_sec, 1_000_000.0);
+        assert_eq!(rate.write_bytes_per_sec, 3_000_000.0);
+        assert_eq!((rate.read_iops, rate.write_iops), (50.0, 150.0));
+        assert_eq!(rate.await_ms, 2.0);
+        assert_eq!(rate.utilization_percent, 50.0);
+        assert_eq!(rate.queue_depth, 1.5);
+        assert_eq!(rate.in_flight, 2);
+        // Contador que voltou (reinício do dispositivo) não gera taxa negativa
+        let reset = compute_rate(&cur, &prev, 1.0);
+        assert_eq!((reset.read_bytes_per_sec, reset.await_ms, reset.utilization_percent), (0.0, 0.0, 0.0));
+    }
+}
@@ -1,8 +1,11 @@
+#[cfg(windows)]
 use std::ffi::OsStr;
+#[cfg(windows)]
 use std::os::windows::ffi::OsStrExt;
 use tokio::task;
 
 // FFI para sistema de arquivos
+#[cfg(windows)]
 #[link(name = "kernel32")]
 extern "system" {
     fn GetLogicalDrives() -> u32;
@@ -15,9 +18,23 @@ extern "system" {
     ) -> i32;
 }
 
+// Valores de GetDriveTypeW
+#[cfg(windows)]
+const DRIVE_REMOVABLE: u32 = 2;
+#[cfg(windows)]
+const DRIVE_FIXED: u32 = 3;
+#[cfg(windows)]
+const DRIVE_REMOTE: u32 = 4;
+#[cfg(windows)]
+const DRIVE_CDROM: u32 = 5;
+#[cfg(windows)]
+const DRIVE_RAMDISK: u32 = 6;
+
 // Função para listar partições
-pub async fn list_partitions() -> Result<Vec<PartitionInfo>, Box<dyn std::error::Error + Send + Sync>> {
-    let partitions = task::spawn_blocking(|| unsafe {
+#[cfg(windows)]
+pub async fn list_partitions(_include_pseudo: bool, include_network: bool) -> Result<Vec<PartitionInfo>, Box<dyn std::error::Error + Send + Sync>> {
+    let span = tracing::Span::current();
+    let partitions = task::spawn_blocking(move || span.in_scope(|| crate::logging::timed("disk_probe", || unsafe {
         let mut result = Vec::new();
         let drives = GetLogicalDrives();
         for i in 0..26 {
@@ -26,7 +43,13 @@ pub async fn list_partitions() -> Result<Vec<PartitionInfo>, Box<dyn std::error:
                 let path = format!("{}:\\", letter);
                 let wpath: Vec<u16> = OsStr::new(&path).encode_wide().chain(Some(0)).collect();
                 let drive_type = GetDriveTypeW(wpath.as_ptr());
-                if drive_type < 2 { continue; }
+                // Unidades de rede podem travar a consulta de espaço se o servidor não responder
+                let kind = match drive_type {
+                    DRIVE_REMOTE if include_network => MountKind::Network,
+                    DRIVE_RAMDISK => MountKind::Tmpfs,
+                    DRIVE_REMOVABLE | DRIVE_FIXED | DRIVE_CDROM => MountKind::Block,
+                    _ => continue,
+                };
                 let mut free = 0u64;
                 let mut total = 0u64;
                 let mut total_free = 0u64;
@@ -39,36 +62,200 @@ pub async fn list_partitions() -> Result<Vec<PartitionInfo>, Box<dyn std::error:
                         free_bytes: total_free,
                         used_bytes: used,
                         percent_used: percent,
+                        // Espaço liberado ao usuário atual, já descontadas as cotas; não há reserva de root a informar
+                        available_bytes: free,
+                        reserved_bytes: None,
+                        block_size: None,
+                        inodes_total: None,
+                        inodes_free: None,
+                        inodes_used: None,
+                        inodes_percent_used: None,
+                        source: None,
+                        mount_point: Some(path.clone()),
+                        fs_type: None,
+                        mount_options: Vec::new(),
+                        super_options: Vec::new(),
+                        propagation: Vec::new(),
+                        mount_id: None,
+                        parent_id: None,
+                        kind: Some(kind),
                     });
                 }
             }
         }
         result
-    }).await.unwrap();
+    }))).await.unwrap();
+    Ok(partitions)
+}
+
+// Função para listar partições a partir da tabela de montagem (/proc/self/mountinfo)
+#[cfg(target_os = "linux")]
+pub async fn list_partitions(include_pseudo: bool, include_network: bool) -> Result<Vec<PartitionInfo>, Box<dyn std::error::Error + Send + Sync>> {
+    let span = tracing::Span::current();
+    let partitions = task::spawn_blocking(move || span.in_scope(|| crate::logging::timed("disk_probe", || -> std::io::Result<Vec<PartitionInfo>> {
+        let content = std::fs::read_to_string("/proc/self/mountinfo")?;
+        let mut result = Vec::new();
+        for entry in mountinfo::parse_mountinfo(&content) {
+            if !wanted(entry.kind, include_pseudo, include_network) {
+                continue;
+            }
+            // statvfs pode demorar em montagens de rede; cada ponto aparece em nível trace
+            let started = std::time::Instant::now();
+            let st = match statvfs(&entry.mount_point) {
+                Ok(st) => Some(st),
+                Err(e) => {
+                    tracing::debug!(mount_point = %entry.mount_point, error = %e, "statvfs falhou");
+                    None
+                }
+            };
+            tracing::trace!(mount_point = %entry.mount_point, elapsed_ms = started.elapsed().as_secs_f64() * 1000.0, "statvfs");
+            result.push(partition_info(entry, st));
+        }
+        Ok(result)
+    }))).await??;
     Ok(partitions)
 }
 
+// Montagens de rede ficam de fora por padrão: statvfs bloqueia sem prazo enquanto o servidor não responde
+// (ex.: NFS montado com "hard") e prenderia a thread da requisição
+#[cfg(target_os = "linux")]
+fn wanted(kind: MountKind, include_pseudo: bool, include_network: bool) -> bool {
+    match kind {
+        MountKind::Pseudo => include_pseudo,
+        MountKind::Network => include_network,
+        _ => true,
+    }
+}
+
+// Sem statvfs (permissão negada, montagem desaparecida) os tamanhos ficam zerados e os campos opcionais nulos
+#[cfg(target_os = "linux")]
+fn partition_info(entry: mountinfo::MountEntry, st: Option<StatVfs>) -> PartitionInfo {
+    let bytes = |blocks: u64| st.map_or(0, |st| blocks * st.fragment_size);
+    let total = bytes(st.map_or(0, |st| st.blocks));
+    let total_free = bytes(st.map_or(0, |st| st.blocks_free));
+    let available = bytes(st.map_or(0, |st| st.blocks_available));
+    let used = total.saturating_sub(total_free);
+    let percent = if total > 0 { (used as f32 / total as f32) * 100.0 } else { 0.0 };
+    // Sistemas sem inodes fixos (btrfs, vfat, pseudo) reportam zero
+    let (inodes_total, inodes_free, inodes_used, inodes_percent_used) = match st {
+        Some(st) if st.files > 0 => {
+            let inodes_used = st.files.saturating_sub(st.files_free);
+            (Some(st.files), Some(st.files_free), Some(inodes_used), Some((inodes_used as f32 / st.files as f32) * 100.0))
+        }
+        _ => (None, None, None, None),
+    };
+    PartitionInfo {
+        name: entry.mount_point.clone(),
+        total_bytes: total,
+        free_bytes: total_free,
+        used_bytes: used,
+        percent_used: percent,
+        available_bytes: available,
+        reserved_bytes: st.map(|_| total_free.saturating_sub(available)),
+        block_size: st.map(|st| st.block_size),
+        inodes_total,
+        inodes_free,
+        inodes_used,
+        inodes_percent_used,
+        source: Some(entry.source),
+        mount_point: Some(entry.mount_point),
+        fs_type: Some(entry.fs_type),
+        mount_options: entry.mount_options,
+        super_options: entry.super_options,
+        propagation: entry.propagation,
+        mount_id: Some(entry.mount_id),
+        parent_id: Some(entry.parent_id),
+        kind: Some(entry.kind),
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy)]
+struct StatVfs {
+    block_size: u64,
+    fragment_size: u64,
+    blocks: u64,
+    blocks_free: u64,
+    blocks_available: u64,
+    files: u64,
+    files_free: u64,
+}
+
+// Helper para obter blocos e inodes de um ponto de montagem via statvfs
+#[cfg(target_os = "linux")]
+fn statvfs(mount_point: &str) -> std::io::Result<StatVfs> {
+    let cpath = std::ffi::CString::new(mount_point).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    let mut st: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(cpath.as_ptr(), &mut st) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(StatVfs {
+        block_size: st.f_bsize as u64,
+        fragment_size: st.f_frsize as u64,
+        blocks: st.f_blocks as u64,
+        blocks_free: st.f_bfree as u64,
+        blocks_available: st.f_bavail as u64,
+        files: st.f_files as u64,
+        files_free: st.f_ffree as u64,
+    })
+}
+
+#[derive(serde::Deserialize, utoipa::IntoParams, Debug, Clone, Default)]
+#[into_params(parameter_in = Query)]
+pub struct PartitionQuery {
+    // proc, sysfs, cgroup etc.; sem efeito no Windows, que não monta sistemas de arquivos virtuais como unidades
+    pub include_pseudo: Option<bool>,
+    // NFS, CIFS e afins no Linux, unidades de rede no Windows; a consulta de espaço pode demorar enquanto o
+    // servidor não responde
+    pub include_network: Option<bool>,
+}
+
 use serde::Serialize;
+use utoipa::ToSchema;
+
+// Classificação da montagem: no Linux a partir de /proc/self/mountinfo; no Windows pelo tipo da unidade
+// (fixa/removível/CD como block, rede como network, RAM disk como tmpfs)
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+#[derive(Serialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MountKind {
+    Block,
+    Bind,
+    Overlay,
+    Tmpfs,
+    Network,
+    Pseudo,
+    Other,
+}
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, ToSchema, Debug, Clone)]
 pub struct PartitionInfo {
     pub name: String,
     pub total_bytes: u64,
     pub free_bytes: u64,
     pub used_bytes: u64,
     pub percent_used: f32,
+    // Livre total (free_bytes) inclui blocos reservados ao root; available_bytes é o que usuários comuns podem usar
+    pub available_bytes: u64,
+    // Só no Linux (blocos reservados ao root); no Windows a diferença entre free e available vem de cotas
+    pub reserved_bytes: Option<u64>,
+    pub block_size: Option<u64>,
+    pub inodes_total: Option<u64>,
+    pub inodes_free: Option<u64>,
+    pub inodes_used: Option<u64>,
+    pub inodes_percent_used: Option<f32>,
+    pub source: Option<String>,
+    pub mount_point: Option<String>,
+    pub fs_type: Option<String>,
+    pub mount_options: Vec<String>,
+    pub super_options: Vec<String>,
+    pub propagation: Vec<String>,
+    pub mount_id: Option<u32>,
+    pub parent_id: Option<u32>,
+    pub kind: Option<MountKind>,
 }
 
-#[derive(Serialize, Debug, Clone)]
-pub struct FileInfo {
-    pub name: String,
-    pub is_dir: bool,
-    pub size: u64,
-    pub readonly: bool,
-    pub hidden: bool,
-}
-
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, ToSchema, Debug, Clone)]
 pub struct DiskInfo {
     pub name: String,
     pub total_bytes: u64,
@@ -79,7 +266,47 @@ pub struct DiskInfo {
     pub is_system: bool,
     pub has_pagefile: bool,
     pub disk_type: String,
+    // Contadores acumulados desde o boot; as taxas por dispositivo ficam em /api/v2/storage/io
     pub read_bytes: u64,
     pub write_bytes: u64,
     pub transfer_bytes: u64,
 }
+
+pub mod blockdev;
+#[cfg(windows)]
+pub mod diskperf;
+pub mod diskstats;
+#[cfg(target_os = "linux")]
+pub mod mountinfo;
+pub mod tail;
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    fn entry(line: &str) -> mountinfo::MountEntry {
+        mountinfo::parse_mountinfo(line).remove(0)
+    }
+
+    #[test]
+    fn failed_statvfs_leaves_optional_fields_null() {
+        let root = entry("25 1 8:2 / / rw,relatime shared:1 - ext4 /dev/sda2 rw\n");
+        let st = StatVfs { block_size: 4096, fragment_size: 4096, blocks: 1000, blocks_free: 300, blocks_available: 250, files: 100, files_free: 40 };
+        let ok = partition_info(root.clone(), Some(st));
+        assert_eq!((ok.total_bytes, ok.free_bytes, ok.used_bytes, ok.available_bytes), (4_096_000, 1_228_800, 2_867_200, 1_024_000));
+        assert_eq!((ok.reserved_bytes, ok.block_size, ok.inodes_used), (Some(204_800), Some(4096), Some(60)));
+
+        let failed = partition_info(root, None);
+        assert_eq!((failed.total_bytes, failed.percent_used), (0, 0.0));
+        assert!(failed.reserved_bytes.is_none() && failed.block_size.is_none());
+        assert!(failed.inodes_total.is_none() && failed.inodes_free.is_none() && failed.inodes_percent_used.is_none());
+        assert_eq!(failed.fs_type.as_deref(), Some("ext4"));
+    }
+
+    #[test]
+    fn network_and_pseudo_mounts_are_opt_in() {
+        assert!(wanted(MountKind::Block, false, false) && wanted(MountKind::Tmpfs, false, false));
+        assert!(!wanted(MountKind::Network, true, false) && wanted(MountKind::Network, false, true));
+        assert!(!wanted(MountKind::Pseudo, false, true) && wanted(MountKind::Pseudo, true, false));
+    }
+}
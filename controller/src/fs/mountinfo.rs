@@ -0,0 +1,186 @@
+// Módulo para interpretar a tabela de montagem do Linux (/proc/self/mountinfo)
+use super::MountKind;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+pub struct MountEntry {
+    pub mount_id: u32,
+    pub parent_id: u32,
+    pub major: u32,
+    pub minor: u32,
+    pub root: String,
+    pub mount_point: String,
+    pub mount_options: Vec<String>,
+    pub propagation: Vec<String>,
+    pub fs_type: String,
+    pub source: String,
+    pub super_options: Vec<String>,
+    pub kind: MountKind,
+}
+
+const PSEUDO_FS: &[&str] = &[
+    "proc", "sysfs", "cgroup", "cgroup2", "devpts", "securityfs", "debugfs", "tracefs",
+    "pstore", "bpf", "configfs", "fusectl", "mqueue", "hugetlbfs", "autofs", "binfmt_misc",
+    "efivarfs", "rpc_pipefs", "nsfs", "selinuxfs",
+];
+const TMPFS_FS: &[&str] = &["tmpfs", "ramfs", "devtmpfs"];
+const OVERLAY_FS: &[&str] = &["overlay", "aufs"];
+const NETWORK_FS: &[&str] = &[
+    "nfs", "nfs4", "cifs", "smb3", "smbfs", "9p", "ceph", "glusterfs", "afs", "fuse.sshfs",
+    "fuse.glusterfs", "fuse.s3fs",
+];
+
+// Desfaz os escapes octais usados pelo kernel (ex.: "\040" para espaço)
+fn unescape(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 4 <= bytes.len() {
+            let digits = &bytes[i + 1..i + 4];
+            if digits.iter().all(|d| (b'0'..=b'7').contains(d)) {
+                out.push(digits.iter().fold(0u8, |acc, d| acc.wrapping_mul(8).wrapping_add(d - b'0')));
+                i += 4;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn classify(fs_type: &str, source: &str, root: &str, bind: bool) -> MountKind {
+    if PSEUDO_FS.contains(&fs_type) {
+        MountKind::Pseudo
+    } else if TMPFS_FS.contains(&fs_type) {
+        MountKind::Tmpfs
+    } else if OVERLAY_FS.contains(&fs_type) {
+        MountKind::Overlay
+    } else if NETWORK_FS.contains(&fs_type) || source.starts_with("//") {
+        MountKind::Network
+    } else if bind || (root != "/" && fs_type != "btrfs") {
+        MountKind::Bind
+    } else if source.starts_with("/dev/") {
+        MountKind::Block
+    } else {
+        MountKind::Other
+    }
+}
+
+fn parse_line(line: &str) -> Option<MountEntry> {
+    let mut fields = line.split_whitespace();
+    let mount_id = fields.next()?.parse().ok()?;
+    let parent_id = fields.next()?.parse().ok()?;
+    let (major, minor) = fields.next()?.split_once(':')?;
+    let root = unescape(fields.next()?);
+    let mount_point = unescape(fields.next()?);
+    let mount_options = fields.next()?.split(',').map(String::from).collect();
+    // Campos opcionais (shared:N, master:N, propagate_from:N, unbindable) até o separador "-"
+    let mut propagation = Vec::new();
+    for f in fields.by_ref() {
+        if f == "-" {
+            break;
+        }
+        propagation.push(f.to_string());
+    }
+    let fs_type = fields.next()?.to_string();
+    let source = unescape(fields.next().unwrap_or("none"));
+    let super_options = fields.next().map(|o| o.split(',').map(String::from).collect()).unwrap_or_default();
+    Some(MountEntry {
+        mount_id,
+        parent_id,
+        major: major.parse().ok()?,
+        minor: minor.parse().ok()?,
+        root,
+        mount_point,
+        mount_options,
+        propagation,
+        fs_type,
+        source,
+        super_options,
+        kind: MountKind::Other,
+    })
+}
+
+// Função para interpretar o conteúdo de /proc/<pid>/mountinfo
+pub fn parse_mountinfo(content: &str) -> Vec<MountEntry> {
+    let mut entries: Vec<MountEntry> = content.lines().filter_map(parse_line).collect();
+    // Um mesmo dispositivo montado de novo (sem ser pseudo) indica bind mount
+    let mut seen_devices = HashSet::new();
+    for entry in entries.iter_mut() {
+        let first_time = seen_devices.insert((entry.major, entry.minor));
+        let bind = !first_time && entry.major != 0;
+        entry.kind = classify(&entry.fs_type, &entry.source, &entry.root, bind);
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unescapes_octal_sequences() {
+        let content = "36 25 8:1 / /mnt/my\\040disk rw,relatime shared:1 - ext4 /dev/sda1 rw\n";
+        let entries = parse_mountinfo(content);
+        assert_eq!(entries[0].mount_point, "/mnt/my disk");
+        assert_eq!(unescape("tab\\011here\\134"), "tab\there\\");
+        // Barra invertida sem três dígitos octais fica como está
+        assert_eq!(unescape("a\\08b\\1"), "a\\08b\\1");
+    }
+
+    #[test]
+    fn reads_optional_fields_before_separator() {
+        let content = "\
+22 1 0:21 / /sys rw,nosuid,nodev,noexec,relatime shared:7 - sysfs sysfs rw
+40 22 0:35 / /sys/fs/cgroup ro,nosuid shared:9 master:2 propagate_from:3 - cgroup2 cgroup2 rw,nsdelegate
+41 22 0:36 / /sys/fs/bpf rw,relatime - bpf bpf rw,mode=700
+";
+        let entries = parse_mountinfo(content);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].propagation, vec!["shared:7"]);
+        assert_eq!(entries[1].propagation, vec!["shared:9", "master:2", "propagate_from:3"]);
+        assert_eq!(entries[1].fs_type, "cgroup2");
+        assert_eq!(entries[1].super_options, vec!["rw", "nsdelegate"]);
+        assert!(entries[2].propagation.is_empty());
+        assert_eq!(entries[2].source, "bpf");
+        assert!(entries.iter().all(|e| e.kind == MountKind::Pseudo));
+    }
+
+    #[test]
+    fn classifies_bind_and_block_mounts() {
+        let content = "\
+25 1 8:2 / / rw,relatime shared:1 - ext4 /dev/sda2 rw
+30 25 8:2 /srv/data /data rw,relatime shared:1 - ext4 /dev/sda2 rw
+31 25 8:3 / /home rw,relatime shared:2 - ext4 /dev/sda3 rw
+32 31 8:3 / /var/lib/home rw,relatime shared:2 - ext4 /dev/sda3 rw
+33 25 0:40 / /tmp rw,nosuid shared:3 - tmpfs tmpfs rw
+34 25 0:41 / /mnt/share rw shared:4 - cifs //server/share rw
+35 25 0:42 / /merged rw - overlay overlay rw,lowerdir=/a
+";
+        let entries = parse_mountinfo(content);
+        let kinds: Vec<(&str, MountKind)> = entries.iter().map(|e| (e.mount_point.as_str(), e.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("/", MountKind::Block),
+                // Subdiretório do mesmo dispositivo
+                ("/data", MountKind::Bind),
+                ("/home", MountKind::Block),
+                // Mesmo dispositivo montado de novo a partir da raiz
+                ("/var/lib/home", MountKind::Bind),
+                ("/tmp", MountKind::Tmpfs),
+                ("/mnt/share", MountKind::Network),
+                ("/merged", MountKind::Overlay),
+            ]
+        );
+        assert_eq!((entries[1].major, entries[1].minor), (8, 2));
+        assert_eq!(entries[1].root, "/srv/data");
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        assert!(parse_mountinfo("garbage\n36 25 8:1 / /mnt\n").is_empty());
+    }
+}
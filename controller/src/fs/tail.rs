@@ -0,0 +1,480 @@
+// Módulo para leitura do final de arquivos de log (tail) e acompanhamento (follow)
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task;
+
+const DEFAULT_LINES: usize = 100;
+const MAX_LINES: usize = 10_000;
+const CHUNK_SIZE: u64 = 64 * 1024;
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Deserialize, IntoParams, Debug, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct TailQuery {
+    pub path: String,
+    pub lines: Option<usize>,
+    pub grep: Option<String>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct TailResult {
+    pub path: String,
+    pub size_bytes: u64,
+    pub lines: Vec<String>,
+}
+
+// Eventos emitidos no modo follow
+#[derive(Serialize, ToSchema, Debug, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TailEvent {
+    Line { line: String },
+    Truncated,
+    Rotated,
+}
+
+#[derive(Debug)]
+pub enum TailError {
+    NotFound,
+    OutsideRoots,
+    InvalidPattern(regex::Error),
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for TailError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TailError::NotFound => write!(f, "arquivo não encontrado"),
+            TailError::OutsideRoots => write!(f, "caminho fora dos diretórios liberados em filesystem.tail_roots"),
+            TailError::InvalidPattern(e) => write!(f, "padrão de filtro inválido: {}", e),
+            TailError::Io(e) => write!(f, "erro de E/S: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TailError {}
+
+impl From<std::io::Error> for TailError {
+    fn from(e: std::io::Error) -> Self {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            TailError::NotFound
+        } else {
+            TailError::Io(e)
+        }
+    }
+}
+
+// Diretórios liberados para tail/follow, canonicalizados na inicialização (links e ".." resolvidos)
+#[derive(Debug, Clone, Default)]
+pub struct TailRoots {
+    roots: Vec<PathBuf>,
+}
+
+impl TailRoots {
+    // Raízes que não existem ficam de fora com um aviso; criadas depois, só valem após reiniciar
+    pub fn from_config(roots: &[PathBuf]) -> Self {
+        let roots = roots
+            .iter()
+            .filter_map(|root| match std::fs::canonicalize(root) {
+                Ok(canonical) => Some(canonical),
+                Err(e) => {
+                    tracing::warn!(root = %root.display(), error = %e, "diretório de filesystem.tail_roots ignorado");
+                    None
+                }
+            })
+            .collect();
+        TailRoots { roots }
+    }
+
+    fn allows(&self, path: &Path) -> bool {
+        path.is_absolute() && self.roots.iter().any(|root| path.starts_with(root))
+    }
+
+    // Caminho canônico do arquivo pedido; o que sair das raízes (inclusive por symlink) é recusado
+    fn resolve(&self, path: &str) -> Result<PathBuf, TailError> {
+        let requested = Path::new(path);
+        match std::fs::canonicalize(requested) {
+            Ok(canonical) if self.allows(&canonical) => Ok(canonical),
+            Ok(_) => Err(TailError::OutsideRoots),
+            // Fora das raízes a resposta não revela se o arquivo existe
+            Err(e) if self.allows(requested) => Err(e.into()),
+            Err(_) => Err(TailError::OutsideRoots),
+        }
+    }
+}
+
+impl TailQuery {
+    fn line_count(&self) -> usize {
+        self.lines.unwrap_or(DEFAULT_LINES).min(MAX_LINES)
+    }
+
+    fn filter(&self) -> Result<Option<Regex>, TailError> {
+        match self.grep.as_deref() {
+            Some(p) if !p.is_empty() => Regex::new(p).map(Some).map_err(TailError::InvalidPattern),
+            _ => Ok(None),
+        }
+    }
+}
+
+// FFI para obter o índice do arquivo no volume (equivalente ao inode)
+#[cfg(windows)]
+#[repr(C)]
+#[derive(Default)]
+struct ByHandleFileInformation {
+    file_attributes: u32,
+    creation_time: [u32; 2],
+    last_access_time: [u32; 2],
+    last_write_time: [u32; 2],
+    volume_serial_number: u32,
+    file_size_high: u32,
+    file_size_low: u32,
+    number_of_links: u32,
+    file_index_high: u32,
+    file_index_low: u32,
+}
+
+#[cfg(windows)]
+#[link(name = "kernel32")]
+extern "system" {
+    fn GetFileInformationByHandle(hFile: *mut std::ffi::c_void, lpFileInformation: *mut ByHandleFileInformation) -> i32;
+}
+
+// Identificador do arquivo usado para detectar rotação (inode no Unix, índice do arquivo no Windows)
+#[cfg(unix)]
+fn file_id(file: &File) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Some(file.metadata().ok()?.ino())
+}
+
+#[cfg(windows)]
+fn file_id(file: &File) -> Option<u64> {
+    use std::os::windows::io::AsRawHandle;
+    let mut info = ByHandleFileInformation::default();
+    if unsafe { GetFileInformationByHandle(file.as_raw_handle(), &mut info) } == 0 {
+        return None;
+    }
+    Some(((info.file_index_high as u64) << 32) | info.file_index_low as u64)
+}
+
+fn matches(filter: &Option<Regex>, line: &str) -> bool {
+    filter.as_ref().is_none_or(|re| re.is_match(line))
+}
+
+// Lê blocos a partir do fim, examinando cada bloco uma única vez, até juntar linhas suficientes que passem no filtro
+fn read_last_lines(file: &mut File, size: u64, count: usize, filter: &Option<Regex>) -> std::io::Result<Vec<String>> {
+    let mut pos = size;
+    // Início da região já examinada; só vira linha completa quando o bloco anterior for lido
+    let mut carry: Vec<u8> = Vec::new();
+    // Linhas selecionadas, da última para a primeira
+    let mut selected: Vec<String> = Vec::new();
+    let mut at_end = true;
+    while selected.len() < count {
+        let start = pos.saturating_sub(CHUNK_SIZE);
+        let mut data = vec![0u8; (pos - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut data)?;
+        data.extend_from_slice(&carry);
+        pos = start;
+
+        let mut lines: Vec<&[u8]> = data.split(|&b| b == b'\n').collect();
+        // O "\n" final do arquivo não abre uma linha nova
+        if at_end && lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+        at_end = false;
+        // A primeira linha pode estar incompleta enquanto não chegamos ao início do arquivo
+        let head = if pos > 0 && !lines.is_empty() { lines.remove(0).to_vec() } else { Vec::new() };
+        for raw in lines.iter().rev() {
+            let line = String::from_utf8_lossy(raw);
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+            if matches(filter, line) {
+                selected.push(line.to_string());
+                if selected.len() >= count {
+                    break;
+                }
+            }
+        }
+        if pos == 0 {
+            break;
+        }
+        carry = head;
+    }
+    selected.reverse();
+    Ok(selected)
+}
+
+// Função para ler as últimas linhas de um arquivo
+pub async fn tail(query: TailQuery, roots: Arc<TailRoots>) -> Result<TailResult, TailError> {
+    let filter = query.filter()?;
+    let count = query.line_count();
+    task::spawn_blocking(move || {
+        let mut file = File::open(roots.resolve(&query.path)?)?;
+        let size = file.metadata()?.len();
+        let lines = read_last_lines(&mut file, size, count, &filter)?;
+        Ok(TailResult { path: query.path, size_bytes: size, lines })
+    })
+    .await
+    .map_err(|e| TailError::Io(std::io::Error::other(e)))?
+}
+
+struct Follower {
+    path: PathBuf,
+    file: File,
+    id: Option<u64>,
+    pos: u64,
+    partial: Vec<u8>,
+    filter: Option<Regex>,
+}
+
+impl Follower {
+    fn open(path: &Path, filter: Option<Regex>, from_end: bool) -> std::io::Result<Self> {
+        let file = File::open(path)?;
+        let pos = if from_end { file.metadata()?.len() } else { 0 };
+        Ok(Follower { path: path.to_path_buf(), id: file_id(&file), file, pos, partial: Vec::new(), filter })
+    }
+
+    // Verifica rotação/truncamento e devolve os eventos com as linhas novas
+    fn poll(&mut self) -> std::io::Result<Vec<TailEvent>> {
+        let mut events = Vec::new();
+        // Abre o caminho de novo para comparar o identificador: no Windows ele só sai de um handle aberto
+        let current = File::open(&self.path).and_then(|f| Ok((file_id(&f), f.metadata()?)));
+        match current {
+            Ok((id, _)) if id.is_some() && id != self.id => {
+                // Antes de trocar, drena o que restou no arquivo antigo
+                self.read_new(&mut events)?;
+                *self = Follower::open(&self.path, self.filter.clone(), false)?;
+                events.push(TailEvent::Rotated);
+            }
+            Ok((_, meta)) if meta.len() < self.pos => {
+                self.pos = 0;
+                self.partial.clear();
+                events.push(TailEvent::Truncated);
+            }
+            // Durante a rotação o arquivo pode não existir por alguns instantes
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(events),
+            Err(e) => return Err(e),
+            Ok(_) => {}
+        }
+        self.read_new(&mut events)?;
+        Ok(events)
+    }
+
+    fn read_new(&mut self, events: &mut Vec<TailEvent>) -> std::io::Result<()> {
+        self.file.seek(SeekFrom::Start(self.pos))?;
+        let mut data = Vec::new();
+        let read = self.file.read_to_end(&mut data)?;
+        self.pos += read as u64;
+        self.partial.extend_from_slice(&data);
+        while let Some(idx) = self.partial.iter().position(|&b| b == b'\n') {
+            let raw: Vec<u8> = self.partial.drain(..=idx).collect();
+            let line = String::from_utf8_lossy(&raw).trim_end_matches(['\n', '\r']).to_string();
+            if matches(&self.filter, &line) {
+                events.push(TailEvent::Line { line });
+            }
+        }
+        Ok(())
+    }
+}
+
+// Bytes depois do último "\n" entre os primeiros `size` bytes (vazio se o arquivo termina em "\n")
+fn trailing_partial(file: &mut File, size: u64) -> std::io::Result<Vec<u8>> {
+    let mut pos = size;
+    let mut partial = Vec::new();
+    while pos > 0 {
+        let start = pos.saturating_sub(CHUNK_SIZE);
+        let mut data = vec![0u8; (pos - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut data)?;
+        let newline = data.iter().rposition(|&b| b == b'\n');
+        if let Some(idx) = newline {
+            data.drain(..=idx);
+        }
+        data.extend_from_slice(&partial);
+        partial = data;
+        if newline.is_some() {
+            break;
+        }
+        pos = start;
+    }
+    Ok(partial)
+}
+
+// Função para acompanhar um arquivo: envia as últimas linhas e depois as novas conforme são anexadas
+pub async fn follow(query: TailQuery, roots: Arc<TailRoots>) -> Result<mpsc::Receiver<TailEvent>, TailError> {
+    let filter = query.filter()?;
+    let mut initial = tail(query.clone(), roots.clone()).await?;
+    let size = initial.size_bytes;
+    let mut follower = task::spawn_blocking(move || -> Result<Follower, TailError> {
+        let mut follower = Follower::open(&roots.resolve(&query.path)?, filter, true)?;
+        // Evita perder linhas escritas entre a leitura inicial e a abertura do follower
+        follower.pos = follower.pos.min(size);
+        // Uma última linha sem "\n" ainda está sendo escrita: fica pendente até completar, em vez de sair partida em duas
+        follower.partial = trailing_partial(&mut follower.file, follower.pos)?;
+        Ok(follower)
+    })
+    .await
+    .map_err(|e| TailError::Io(std::io::Error::other(e)))??;
+    // A leitura inicial já devolveu o início dessa linha; ela sai uma vez só, completa, pelo follower
+    let pending = String::from_utf8_lossy(&follower.partial).into_owned();
+    if !pending.is_empty() && initial.lines.last().map(String::as_str) == Some(pending.strip_suffix('\r').unwrap_or(&pending)) {
+        initial.lines.pop();
+    }
+
+    let (tx, rx) = mpsc::channel(256);
+    tokio::spawn(async move {
+        for line in initial.lines {
+            if tx.send(TailEvent::Line { line }).await.is_err() {
+                return;
+            }
+        }
+        loop {
+            tokio::time::sleep(FOLLOW_POLL_INTERVAL).await;
+            // O cliente desconectou
+            if tx.is_closed() {
+                return;
+            }
+            let (returned, events) = match task::spawn_blocking(move || {
+                let events = follower.poll();
+                (follower, events)
+            })
+            .await
+            {
+                Ok(r) => r,
+                Err(_) => return,
+            };
+            follower = returned;
+            let events = match events {
+                Ok(ev) => ev,
+                Err(_) => return,
+            };
+            for ev in events {
+                if tx.send(ev).await.is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    Ok(rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+    use std::io::Write;
+
+    fn last_lines(path: &Path, count: usize, grep: Option<&str>) -> Vec<String> {
+        let mut file = File::open(path).unwrap();
+        let size = file.metadata().unwrap().len();
+        read_last_lines(&mut file, size, count, &grep.map(|g| Regex::new(g).unwrap())).unwrap()
+    }
+
+    #[test]
+    fn reads_last_lines_across_chunks() {
+        // Linhas atravessando vários blocos de 64 KiB, com CRLF e uma linha vazia
+        let mut content = Vec::new();
+        for i in 0..20_000 {
+            content.extend_from_slice(format!("linha {}\r\n", i).as_bytes());
+        }
+        content.extend_from_slice(b"\nfim");
+        let dir = TempDir::new("tail-chunks");
+        let path = dir.write("app.log", &content);
+        assert_eq!(last_lines(&path, 3, None), vec!["linha 19999", "", "fim"]);
+        assert_eq!(last_lines(&path, 2, Some("^linha 1000[0-9]$")), vec!["linha 10008", "linha 10009"]);
+        // Filtro raro: percorre o arquivo inteiro e devolve o que encontrou
+        assert_eq!(last_lines(&path, 5, Some("^linha 7$")), vec!["linha 7"]);
+    }
+
+    #[test]
+    fn handles_trailing_newline_and_empty_file() {
+        let dir = TempDir::new("tail-small");
+        let path = dir.write("app.log", b"a\nb\n\nc\n");
+        assert_eq!(last_lines(&path, 10, None), vec!["a", "b", "", "c"]);
+        std::fs::write(&path, b"").unwrap();
+        assert!(last_lines(&path, 10, None).is_empty());
+    }
+
+    #[test]
+    fn follower_keeps_filter_after_rotation() {
+        let dir = TempDir::new("tail-rotate");
+        let path = dir.write("app.log", b"");
+        let mut follower = Follower::open(&path, Some(Regex::new("erro").unwrap()), true).unwrap();
+        // Rotação por rename: o arquivo antigo continua aberto, então o novo tem outro inode
+        let rotated = dir.write("app.log.new", b"ok\nerro 1\n");
+        std::fs::rename(&rotated, &path).unwrap();
+        let events = follower.poll().unwrap();
+        assert!(matches!(events.last(), Some(TailEvent::Line { line }) if line == "erro 1"));
+        assert!(follower.filter.is_some());
+    }
+
+    #[test]
+    fn finds_trailing_partial_line() {
+        let dir = TempDir::new("tail-partial");
+        let path = dir.write("app.log", b"a\nb\nmeia lin");
+        let mut file = File::open(&path).unwrap();
+        assert_eq!(trailing_partial(&mut file, 12).unwrap(), b"meia lin");
+        assert_eq!(trailing_partial(&mut file, 4).unwrap(), b"");
+        // Linha maior que um bloco e arquivo sem nenhum "\n"
+        let long = vec![b'x'; CHUNK_SIZE as usize + 10];
+        let path = dir.write("long.log", &long);
+        assert_eq!(trailing_partial(&mut File::open(&path).unwrap(), long.len() as u64).unwrap(), long);
+    }
+
+    async fn next_line(rx: &mut mpsc::Receiver<TailEvent>) -> String {
+        match tokio::time::timeout(Duration::from_secs(5), rx.recv()).await.unwrap() {
+            Some(TailEvent::Line { line }) => line,
+            other => panic!("esperava uma linha, veio {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn follow_completes_line_cut_by_initial_tail() {
+        let dir = TempDir::new("tail-follow");
+        let path = dir.write("app.log", b"um\ndois\ntr");
+        let query = TailQuery { path: path.to_string_lossy().to_string(), lines: Some(10), grep: None };
+        let mut rx = follow(query, Arc::new(TailRoots::from_config(&[dir.path().to_path_buf()]))).await.unwrap();
+        assert_eq!(next_line(&mut rx).await, "um");
+        assert_eq!(next_line(&mut rx).await, "dois");
+        std::fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(b"es\nquatro\n").unwrap();
+        // A linha sai inteira, não só o sufixo escrito depois da leitura inicial
+        assert_eq!(next_line(&mut rx).await, "tres");
+        assert_eq!(next_line(&mut rx).await, "quatro");
+    }
+
+    #[test]
+    fn only_resolves_paths_inside_roots() {
+        let dir = TempDir::new("tail-roots");
+        let log = dir.write("logs/app.log", b"ok\n");
+        let secret = dir.write("secret/config.toml", b"token = 1\n");
+        let roots = TailRoots::from_config(&[dir.path().join("logs"), dir.path().join("missing")]);
+        let path = |p: &Path| p.to_string_lossy().to_string();
+
+        assert_eq!(roots.resolve(&path(&log)).unwrap(), std::fs::canonicalize(&log).unwrap());
+        assert!(matches!(roots.resolve(&path(&secret)), Err(TailError::OutsideRoots)));
+        // ".." é resolvido antes da comparação
+        assert!(matches!(roots.resolve(&path(&dir.path().join("logs/../secret/config.toml"))), Err(TailError::OutsideRoots)));
+        // Dentro das raízes, arquivo inexistente é 404; fora, não se revela se existe
+        assert!(matches!(roots.resolve(&path(&dir.path().join("logs/none.log"))), Err(TailError::NotFound)));
+        assert!(matches!(roots.resolve(&path(&dir.path().join("secret/none"))), Err(TailError::OutsideRoots)));
+        assert!(matches!(roots.resolve("logs/app.log"), Err(TailError::OutsideRoots)));
+        assert!(matches!(TailRoots::default().resolve(&path(&log)), Err(TailError::OutsideRoots)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_out_of_roots_is_rejected() {
+        let dir = TempDir::new("tail-symlink");
+        let secret = dir.write("secret/config.toml", b"token = 1\n");
+        dir.write("logs/app.log", b"ok\n");
+        std::os::unix::fs::symlink(&secret, dir.path().join("logs/link.log")).unwrap();
+        let roots = TailRoots::from_config(&[dir.path().join("logs")]);
+        let link = dir.path().join("logs/link.log").to_string_lossy().to_string();
+        assert!(matches!(roots.resolve(&link), Err(TailError::OutsideRoots)));
+    }
+}
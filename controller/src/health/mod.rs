@@ -0,0 +1,249 @@
+// Módulo de saúde do próprio controller: prontidão do sampler, uso de recursos e métricas das requisições
+use axum::extract::{MatchedPath, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Instant;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+use crate::sampler::{self, TickTiming};
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct Readiness {
+    pub ready: bool,
+    pub last_tick: Option<i64>,
+    pub age_ms: Option<i64>,
+    pub max_age_ms: i64,
+}
+
+// Pronto quando o sampler concluiu um tick nos últimos max_intervals intervalos
+pub fn readiness(max_intervals: u32) -> Readiness {
+    let (last_tick, interval_ms) = sampler::tick_status();
+    readiness_at(chrono::Utc::now().timestamp_millis(), last_tick, interval_ms, max_intervals)
+}
+
+fn readiness_at(now: i64, last_tick: Option<i64>, interval_ms: u64, max_intervals: u32) -> Readiness {
+    let max_age_ms = interval_ms as i64 * max_intervals as i64;
+    // Relógio ajustado para trás não deixa a idade negativa
+    let age_ms = last_tick.map(|t| (now - t).max(0));
+    Readiness { ready: age_ms.is_some_and(|age| age <= max_age_ms), last_tick, age_ms, max_age_ms }
+}
+
+// Uso de recursos do próprio processo, medido a cada tick do sampler
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct OwnUsage {
+    pub rss_kb: u64,
+    // Média desde o tick anterior; pode passar de 100% com várias CPUs
+    pub cpu_percent: f32,
+    pub run_time_secs: u64,
+    // Processos vistos na última listagem feita por uma requisição. O sampler não percorre os processos (seria uma
+    // varredura completa a cada tick só para esta contagem), então o campo fica fora da resposta até a primeira listagem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracked_processes: Option<usize>,
+}
+
+pub struct OwnCollector {
+    system: System,
+    pid: Option<Pid>,
+}
+
+impl Default for OwnCollector {
+    fn default() -> Self {
+        OwnCollector { system: System::new(), pid: sysinfo::get_current_pid().ok() }
+    }
+}
+
+impl OwnCollector {
+    pub fn sample(&mut self) -> Option<OwnUsage> {
+        // Só o próprio processo, e só memória e CPU
+        let pid = self.pid?;
+        self.system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, ProcessRefreshKind::nothing().with_memory().with_cpu());
+        let process = self.system.process(pid)?;
+        Some(OwnUsage {
+            rss_kb: process.memory() / 1024,
+            cpu_percent: process.cpu_usage(),
+            run_time_secs: process.run_time(),
+            tracked_processes: *PROCESS_COUNT.lock().unwrap(),
+        })
+    }
+}
+
+static PROCESS_COUNT: Mutex<Option<usize>> = Mutex::new(None);
+
+// Chamado pelas listagens de processos, que já percorrem todos eles
+pub fn record_process_count(count: usize) {
+    *PROCESS_COUNT.lock().unwrap() = Some(count);
+}
+
+#[derive(Default)]
+struct StepStats {
+    count: u64,
+    last_ms: f64,
+    max_ms: f64,
+}
+
+// Etapas caras que rodam no caminho das requisições (varredura de processos, enumeração de handles etc.)
+static STEPS: Lazy<Mutex<BTreeMap<&'static str, StepStats>>> = Lazy::new(Default::default);
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct StepMetrics {
+    pub step: String,
+    pub count: u64,
+    pub last_ms: f64,
+    pub max_ms: f64,
+}
+
+pub fn record_step(step: &'static str, elapsed_ms: f64) {
+    let mut steps = STEPS.lock().unwrap();
+    let stats = steps.entry(step).or_default();
+    stats.count += 1;
+    stats.last_ms = elapsed_ms;
+    stats.max_ms = stats.max_ms.max(elapsed_ms);
+}
+
+pub fn step_metrics() -> Vec<StepMetrics> {
+    STEPS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(step, s)| StepMetrics { step: step.to_string(), count: s.count, last_ms: s.last_ms, max_ms: s.max_ms })
+        .collect()
+}
+
+#[derive(Default)]
+struct RouteStats {
+    count: u64,
+    errors: u64,
+    total_ms: f64,
+    max_ms: f64,
+}
+
+static ROUTES: Lazy<Mutex<BTreeMap<String, RouteStats>>> = Lazy::new(Default::default);
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct RouteMetrics {
+    pub route: String,
+    pub count: u64,
+    // Respostas 5xx
+    pub errors: u64,
+    pub avg_ms: f64,
+    pub max_ms: f64,
+}
+
+// Middleware (route_layer) que conta requisições e mede a latência até a resposta, por rota registrada
+pub async fn track(request: Request, next: Next) -> Response {
+    let route = request.extensions().get::<MatchedPath>().map(|p| p.as_str().to_string());
+    let started = Instant::now();
+    let response = next.run(request).await;
+    if let Some(route) = route {
+        let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+        let mut routes = ROUTES.lock().unwrap();
+        let stats = routes.entry(route).or_default();
+        stats.count += 1;
+        stats.total_ms += elapsed_ms;
+        stats.max_ms = stats.max_ms.max(elapsed_ms);
+        if response.status().is_server_error() {
+            stats.errors += 1;
+        }
+    }
+    response
+}
+
+pub fn route_metrics() -> Vec<RouteMetrics> {
+    ROUTES
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(route, s)| RouteMetrics {
+            route: route.clone(),
+            count: s.count,
+            errors: s.errors,
+            avg_ms: if s.count > 0 { s.total_ms / s.count as f64 } else { 0.0 },
+            max_ms: s.max_ms,
+        })
+        .collect()
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct SamplerMetrics {
+    pub interval_ms: u64,
+    pub ticks: u64,
+    pub last_tick: Option<i64>,
+    pub last: Option<TickTiming>,
+    // Sobre os ticks ainda no histórico (sampler.retention)
+    pub avg_ms: Option<f64>,
+    pub max_ms: Option<f64>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct SelfInfo {
+    pub pid: u32,
+    pub version: String,
+    pub usage: Option<OwnUsage>,
+    pub sampler: SamplerMetrics,
+    pub steps: Vec<StepMetrics>,
+    pub routes: Vec<RouteMetrics>,
+}
+
+pub fn self_info() -> SelfInfo {
+    let timings = sampler::tick_timings();
+    let (last_tick, interval_ms) = sampler::tick_status();
+    let totals: Vec<f64> = timings.iter().map(|t| t.value.total_ms).collect();
+    SelfInfo {
+        pid: std::process::id(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        usage: sampler::own_usage(),
+        sampler: SamplerMetrics {
+            interval_ms,
+            ticks: sampler::tick_count(),
+            last_tick,
+            last: timings.last().map(|t| t.value.clone()),
+            avg_ms: (!totals.is_empty()).then(|| totals.iter().sum::<f64>() / totals.len() as f64),
+            max_ms: totals.iter().copied().reduce(f64::max),
+        },
+        steps: step_metrics(),
+        routes: route_metrics(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_ready_before_first_tick() {
+        let r = readiness_at(10_000, None, 2000, 3);
+        assert!(!r.ready);
+        assert_eq!((r.age_ms, r.max_age_ms), (None, 6000));
+    }
+
+    #[test]
+    fn ready_up_to_max_intervals() {
+        assert!(readiness_at(10_000, Some(9_000), 2000, 3).ready);
+        let r = readiness_at(10_000, Some(4_000), 2000, 3);
+        assert_eq!(r.age_ms, Some(6000));
+        assert!(r.ready);
+        let r = readiness_at(10_000, Some(3_999), 2000, 3);
+        assert_eq!(r.age_ms, Some(6001));
+        assert!(!r.ready);
+    }
+
+    #[test]
+    fn tracked_processes_omitted_until_first_listing() {
+        let usage = |tracked_processes| OwnUsage { rss_kb: 1024, cpu_percent: 0.5, run_time_secs: 10, tracked_processes };
+        let json = serde_json::to_value(usage(None)).unwrap();
+        assert!(json.get("tracked_processes").is_none());
+        assert_eq!(serde_json::to_value(usage(Some(312))).unwrap()["tracked_processes"], 312);
+    }
+
+    #[test]
+    fn tick_in_the_future_counts_as_fresh() {
+        let r = readiness_at(10_000, Some(12_000), 2000, 1);
+        assert_eq!(r.age_ms, Some(0));
+        assert!(r.ready);
+    }
+}
@@ -0,0 +1,114 @@
+// Módulo de logs estruturados: subscriber do tracing, spans por requisição e nível ajustável em tempo de execução
+use axum::extract::Request;
+use axum::http::{HeaderName, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::Instrument;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+use crate::config::{LogConfig, LogFormat};
+
+const REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+// Aceita "info", "debug" ou diretivas por módulo como "info,controller::process=debug"
+pub fn parse_filter(directives: &str) -> Result<EnvFilter, anyhow::Error> {
+    EnvFilter::try_new(directives).map_err(|e| anyhow::anyhow!("filtro de log inválido '{}': {}", directives, e))
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone)]
+pub struct LogLevel {
+    pub level: String,
+}
+
+// Permite trocar o filtro do subscriber já instalado
+#[derive(Clone)]
+pub struct LevelHandle {
+    handle: reload::Handle<EnvFilter, Registry>,
+    current: Arc<Mutex<String>>,
+}
+
+impl LevelHandle {
+    pub fn get(&self) -> LogLevel {
+        LogLevel { level: self.current.lock().unwrap().clone() }
+    }
+
+    pub fn set(&self, directives: &str) -> Result<LogLevel, anyhow::Error> {
+        let filter = parse_filter(directives)?;
+        self.handle.reload(filter)?;
+        *self.current.lock().unwrap() = directives.to_string();
+        Ok(self.get())
+    }
+}
+
+// Instala o subscriber global; validate() já conferiu o filtro
+pub fn init(config: &LogConfig) -> Result<LevelHandle, anyhow::Error> {
+    let (filter, handle) = reload::Layer::new(parse_filter(&config.level)?);
+    let registry = tracing_subscriber::registry().with(filter);
+    match config.format {
+        LogFormat::Text => registry.with(tracing_subscriber::fmt::layer()).try_init()?,
+        LogFormat::Json => registry.with(tracing_subscriber::fmt::layer().json().flatten_event(true).with_current_span(true).with_span_list(false)).try_init()?,
+    }
+    Ok(LevelHandle { handle, current: Arc::new(Mutex::new(config.level.clone())) })
+}
+
+static NEXT_REQUEST: AtomicU64 = AtomicU64::new(1);
+
+// IDs curtos e únicos por execução: segundos do início do processo + contador
+fn new_request_id() -> String {
+    static PREFIX: once_cell::sync::Lazy<u32> = once_cell::sync::Lazy::new(|| chrono::Utc::now().timestamp() as u32);
+    format!("{:08x}-{:06x}", *PREFIX, NEXT_REQUEST.fetch_add(1, Ordering::Relaxed))
+}
+
+// Reaproveita o X-Request-Id de um proxy na frente do controller quando for um valor razoável
+fn incoming_request_id(request: &Request) -> Option<String> {
+    let value = request.headers().get(&REQUEST_ID)?.to_str().ok()?;
+    let valid = !value.is_empty() && value.len() <= 64 && value.chars().all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c));
+    valid.then(|| value.to_string())
+}
+
+// Abre um span por requisição (id, método, caminho e, depois da autenticação, a identidade) e registra a conclusão
+pub async fn trace_request(request: Request, next: Next) -> Response {
+    let id = incoming_request_id(&request).unwrap_or_else(new_request_id);
+    let span = tracing::info_span!(
+        "request",
+        id = %id,
+        method = %request.method(),
+        path = %request.uri().path(),
+        identity = tracing::field::Empty,
+    );
+    let started = Instant::now();
+    let mut response = next.run(request).instrument(span.clone()).await;
+    let status = response.status().as_u16();
+    let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+    span.in_scope(|| match status {
+        500.. => tracing::error!(status, elapsed_ms, "requisição concluída"),
+        400.. => tracing::warn!(status, elapsed_ms, "requisição concluída"),
+        _ => tracing::info!(status, elapsed_ms, "requisição concluída"),
+    });
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(REQUEST_ID, value);
+    }
+    response
+}
+
+// Executa e devolve o valor com a duração em ms; base de timed() e das medições do sampler
+pub fn measure<T>(run: impl FnOnce() -> T) -> (T, f64) {
+    let started = Instant::now();
+    let value = run();
+    (value, started.elapsed().as_secs_f64() * 1000.0)
+}
+
+// Executa uma etapa cara, registra sua duração (em debug) no span atual e a acumula para o /api/v2/self
+pub fn timed<T>(step: &'static str, run: impl FnOnce() -> T) -> T {
+    let (value, elapsed_ms) = measure(run);
+    tracing::debug!(step, elapsed_ms, "etapa concluída");
+    crate::health::record_step(step, elapsed_ms);
+    value
+}
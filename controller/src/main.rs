@@ -1,24 +1,119 @@
 mod system;
 mod process;
 mod fs;
-use axum::{routing::get, Router, Json, extract::Path};
+mod network;
+mod sampler;
+mod config;
+mod auth;
+mod tls;
+mod audit;
+mod ui;
+mod api;
+mod health;
+mod logging;
+mod sysfs;
+#[cfg(test)]
+mod testutil;
+use axum::{routing::get, Router, Json, Extension, extract::{Path, Query, State}};
+use clap::Parser;
+use std::sync::Arc;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt};
 // use std::collections::HashMap;
 
 // --- Endpoints delegando para módulos ---
-async fn list_partitions() -> axum::response::Result<Json<Vec<fs::PartitionInfo>>, axum::http::StatusCode> {
-    match fs::list_partitions().await {
+#[utoipa::path(get, path = "/api/v2/filesystem/partitions", tag = "filesystem", params(fs::PartitionQuery), responses((status = 200, body = [fs::PartitionInfo]), (status = 500, description = "Falha ao coletar os dados")))]
+async fn list_partitions(Query(query): Query<fs::PartitionQuery>) -> axum::response::Result<Json<Vec<fs::PartitionInfo>>, axum::http::StatusCode> {
+    match fs::list_partitions(query.include_pseudo.unwrap_or(false), query.include_network.unwrap_or(false)).await {
         Ok(partitions) => Ok(Json(partitions)),
         Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
 
-async fn list_processes() -> axum::response::Result<Json<Vec<process::ProcessInfo>>, axum::http::StatusCode> {
-    match process::list_processes().await {
+// Aplica as regras de acesso ao ambiente e a redação de segredos antes de serializar
+fn prepare_process(p: &mut process::ProcessInfo, identity: &auth::Identity, redactor: &process::redact::Redactor, unredacted: bool) {
+    // Variáveis de ambiente só para admin
+    if !identity.is_admin() {
+        p.environment = None;
+    }
+    if !unredacted {
+        redactor.redact_process(p);
+    }
+}
+
+// Quem recebe o ambiente (admin) entra na auditoria, com ou sem redação; os demais nem o recebem
+fn environment_audit(identity: &auth::Identity, unredacted: bool) -> Option<Extension<audit::AuditDetails>> {
+    identity.is_admin().then(|| Extension(audit::AuditDetails::from([("environment", if unredacted { "unredacted" } else { "redacted" })])))
+}
+
+// Valores sem redação só para admin e só quando pedidos explicitamente
+fn unredacted_requested(query: &process::ProcessQuery, identity: &auth::Identity) -> Result<bool, axum::http::StatusCode> {
+    let unredacted = query.unredacted.unwrap_or(false);
+    if unredacted && !identity.is_admin() {
+        return Err(axum::http::StatusCode::FORBIDDEN);
+    }
+    Ok(unredacted)
+}
+
+#[utoipa::path(get, path = "/api/v2/processes", tag = "processes", responses((status = 200, body = [process::ProcessSummary]), (status = 500, description = "Falha ao coletar os dados")))]
+async fn list_processes() -> axum::response::Result<Json<Vec<process::ProcessSummary>>, axum::http::StatusCode> {
+    match process::list_process_summaries().await {
         Ok(processes) => Ok(Json(processes)),
         Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
 
+#[utoipa::path(get, path = "/api/v2/processes/{pid}", tag = "processes", params(("pid" = u32, Path, description = "PID do processo"), process::ProcessQuery), responses((status = 200, body = process::ProcessInfo), (status = 403, description = "unredacted=true sem papel admin"), (status = 404, description = "Processo não encontrado"), (status = 500, description = "Falha ao coletar os dados")))]
+async fn get_process(
+    Path(pid): Path<u32>,
+    Extension(identity): Extension<auth::Identity>,
+    Extension(redactor): Extension<Arc<process::redact::Redactor>>,
+    Query(query): Query<process::ProcessQuery>,
+) -> axum::response::Result<(Option<Extension<audit::AuditDetails>>, Json<process::ProcessInfo>), axum::http::StatusCode> {
+    let unredacted = unredacted_requested(&query, &identity)?;
+    match process::get_process(pid).await {
+        Ok(Some(mut p)) => {
+            prepare_process(&mut p, &identity, &redactor, unredacted);
+            Ok((environment_audit(&identity, unredacted), Json(p)))
+        }
+        Ok(None) => Err(axum::http::StatusCode::NOT_FOUND),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+// v1: lista completa com recursos abertos em texto
+async fn list_processes_v1(
+    Extension(identity): Extension<auth::Identity>,
+    Extension(redactor): Extension<Arc<process::redact::Redactor>>,
+    Query(query): Query<process::ProcessQuery>,
+) -> axum::response::Result<(Option<Extension<audit::AuditDetails>>, Json<Vec<api::v1::ProcessInfo>>), axum::http::StatusCode> {
+    let unredacted = unredacted_requested(&query, &identity)?;
+    match process::list_processes().await {
+        Ok(processes) => Ok((
+            environment_audit(&identity, unredacted),
+            Json(
+                processes
+                    .into_iter()
+                    .map(|mut p| {
+                        prepare_process(&mut p, &identity, &redactor, unredacted);
+                        api::v1::ProcessInfo::from(p)
+                    })
+                    .collect(),
+            ),
+        )),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+// v1: percentuais e uptime como texto
+async fn get_system_info_v1() -> axum::response::Result<Json<api::v1::SystemInfo>, axum::http::StatusCode> {
+    match system::get_system_info().await {
+        Ok(info) => Ok(Json(info.into())),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/system", tag = "system", responses((status = 200, body = system::SystemInfo), (status = 500, description = "Falha ao coletar os dados")))]
 async fn get_system_info() -> axum::response::Result<Json<system::SystemInfo>, axum::http::StatusCode> {
     match system::get_system_info().await {
         Ok(info) => Ok(Json(info)),
@@ -26,6 +121,98 @@ async fn get_system_info() -> axum::response::Result<Json<system::SystemInfo>, a
     }
 }
 
+// times vem do sampler (/proc/stat): null antes do segundo tick e fora do Linux, onde não há detalhamento por
+// modo; frequencies vem do cpufreq e fica vazia onde ele não existe
+#[derive(serde::Serialize, utoipa::ToSchema)]
+struct CpuDetails {
+    times: Option<system::cpu::CpuBreakdown>,
+    frequencies: Vec<system::cpu::CoreFrequency>,
+}
+
+#[utoipa::path(get, path = "/api/v2/system/cpu", tag = "cpu", responses((status = 200, body = CpuDetails, description = "times é null antes do segundo tick do sampler e fora do Linux")))]
+async fn get_cpu_details() -> Json<CpuDetails> {
+    Json(CpuDetails {
+        times: sampler::latest_cpu_times(),
+        frequencies: tokio::task::spawn_blocking(system::cpu::read_frequencies).await.unwrap_or_default(),
+    })
+}
+
+#[utoipa::path(get, path = "/api/v2/system/cpu/history", tag = "cpu", responses((status = 200, body = [sampler::Sample<system::cpu::CpuBreakdown>])))]
+async fn get_cpu_times_history() -> Json<Vec<sampler::Sample<system::cpu::CpuBreakdown>>> {
+    Json(sampler::cpu_times_history())
+}
+
+#[utoipa::path(get, path = "/api/v2/cpu", tag = "cpu", responses((status = 200, body = system::topology::CpuTopology), (status = 500, description = "Falha ao coletar os dados")))]
+async fn get_cpu_topology() -> axum::response::Result<Json<system::topology::CpuTopology>, axum::http::StatusCode> {
+    match system::topology::get_cpu_topology().await {
+        Ok(topology) => Ok(Json(topology)),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+// Leituras que dependem do /proc devolvem Unsupported fora do Linux: 501, não falha do servidor
+fn io_error_status(err: &std::io::Error) -> axum::http::StatusCode {
+    match err.kind() {
+        std::io::ErrorKind::Unsupported => axum::http::StatusCode::NOT_IMPLEMENTED,
+        _ => axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/system/load", tag = "system", responses((status = 200, body = system::load::LoadInfo), (status = 500, description = "Falha ao coletar os dados"), (status = 501, description = "Plataforma sem /proc (disponível só no Linux)")))]
+async fn get_system_load() -> axum::response::Result<Json<system::load::LoadInfo>, axum::http::StatusCode> {
+    match tokio::task::spawn_blocking(system::load::read_load).await {
+        Ok(Ok(load)) => Ok(Json(load)),
+        Ok(Err(e)) => Err(io_error_status(&e)),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/system/load/history", tag = "system", responses((status = 200, body = [sampler::Sample<system::load::LoadInfo>])))]
+async fn get_system_load_history() -> Json<Vec<sampler::Sample<system::load::LoadInfo>>> {
+    Json(sampler::load_history())
+}
+
+#[utoipa::path(get, path = "/api/v2/system/memory", tag = "system", responses((status = 200, body = system::memory::MemoryDetails), (status = 500, description = "Falha ao coletar os dados"), (status = 501, description = "Plataforma sem /proc (disponível só no Linux)")))]
+async fn get_memory_details() -> axum::response::Result<Json<system::memory::MemoryDetails>, axum::http::StatusCode> {
+    match tokio::task::spawn_blocking(system::memory::read_memory).await {
+        Ok(Ok(mut details)) => {
+            details.swap_rate = sampler::latest_swap_rate();
+            Ok(Json(details))
+        }
+        Ok(Err(e)) => Err(io_error_status(&e)),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/system/memory/swap/history", tag = "system", responses((status = 200, body = [sampler::Sample<system::memory::SwapRate>])))]
+async fn get_swap_history() -> Json<Vec<sampler::Sample<system::memory::SwapRate>>> {
+    Json(sampler::swap_history())
+}
+
+#[utoipa::path(get, path = "/api/v2/sensors", tag = "sensors", responses((status = 200, body = [system::sensors::SensorReading])))]
+async fn get_sensors() -> Json<Vec<system::sensors::SensorReading>> {
+    Json(tokio::task::spawn_blocking(system::sensors::read_sensors).await.unwrap_or_default())
+}
+
+#[utoipa::path(get, path = "/api/v2/sensors/history", tag = "sensors", responses((status = 200, body = [sampler::Sample<Vec<system::sensors::SensorReading>>])))]
+async fn get_sensors_history() -> Json<Vec<sampler::Sample<Vec<system::sensors::SensorReading>>>> {
+    Json(sampler::sensors_history())
+}
+
+#[utoipa::path(get, path = "/api/v2/system/interrupts", tag = "system", responses((status = 200, body = system::interrupts::InterruptsSnapshot), (status = 500, description = "Falha ao coletar os dados"), (status = 501, description = "Plataforma sem /proc (disponível só no Linux)")))]
+async fn get_interrupts() -> axum::response::Result<Json<system::interrupts::InterruptsSnapshot>, axum::http::StatusCode> {
+    // Antes do segundo tick do sampler não há deltas; devolve a leitura crua
+    if let Some(snapshot) = sampler::latest_interrupts() {
+        return Ok(Json(snapshot));
+    }
+    match tokio::task::spawn_blocking(system::interrupts::read_interrupts).await {
+        Ok(Ok(snapshot)) => Ok(Json(snapshot)),
+        Ok(Err(e)) => Err(io_error_status(&e)),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/processes/{pid}/handles", tag = "processes", params(("pid" = u32, Path, description = "PID do processo")), responses((status = 200, body = [process::handle::HandleInfo]), (status = 500, description = "Falha ao coletar os dados")))]
 async fn list_process_handles(Path(pid): Path<u32>) -> axum::response::Result<Json<Vec<process::handle::HandleInfo>>, axum::http::StatusCode> {
     match process::handle::list_process_handles(pid).await {
         Ok(handles) => Ok(Json(handles)),
@@ -33,17 +220,417 @@ async fn list_process_handles(Path(pid): Path<u32>) -> axum::response::Result<Js
     }
 }
 
-// --- Função principal: inicializa o servidor HTTP ---
-#[tokio::main]
-async fn main() {
-    std::env::set_var("RUST_BACKTRACE", "1");
+fn tail_error_status(err: &fs::tail::TailError) -> axum::http::StatusCode {
+    match err {
+        fs::tail::TailError::NotFound => axum::http::StatusCode::NOT_FOUND,
+        fs::tail::TailError::OutsideRoots => axum::http::StatusCode::FORBIDDEN,
+        fs::tail::TailError::InvalidPattern(_) => axum::http::StatusCode::BAD_REQUEST,
+        fs::tail::TailError::Io(_) => axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/filesystem/tail", tag = "filesystem", params(fs::tail::TailQuery), responses((status = 200, body = fs::tail::TailResult), (status = 400, description = "Regex de grep inválida"), (status = 403, description = "Requer papel admin; também para caminhos fora de filesystem.tail_roots"), (status = 404, description = "Arquivo não encontrado")))]
+async fn tail_file(Extension(roots): Extension<Arc<fs::tail::TailRoots>>, Query(query): Query<fs::tail::TailQuery>) -> axum::response::Result<Json<fs::tail::TailResult>, axum::http::StatusCode> {
+    match fs::tail::tail(query, roots).await {
+        Ok(result) => Ok(Json(result)),
+        Err(e) => Err(tail_error_status(&e)),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/filesystem/tail/follow", tag = "filesystem", params(fs::tail::TailQuery), responses((status = 200, description = "Stream SSE de eventos TailEvent", content_type = "text/event-stream", body = fs::tail::TailEvent), (status = 400, description = "Regex de grep inválida"), (status = 403, description = "Requer papel admin; também para caminhos fora de filesystem.tail_roots"), (status = 404, description = "Arquivo não encontrado")))]
+async fn follow_file(Extension(roots): Extension<Arc<fs::tail::TailRoots>>, Query(query): Query<fs::tail::TailQuery>) -> axum::response::Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, axum::http::StatusCode> {
+    match fs::tail::follow(query, roots).await {
+        Ok(rx) => {
+            let stream = ReceiverStream::new(rx).map(|ev| Event::default().json_data(ev));
+            Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+        }
+        Err(e) => Err(tail_error_status(&e)),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/storage/devices", tag = "storage", responses((status = 200, body = [fs::blockdev::BlockDevice]), (status = 500, description = "Falha ao coletar os dados")))]
+async fn list_block_devices() -> axum::response::Result<Json<Vec<fs::blockdev::BlockDevice>>, axum::http::StatusCode> {
+    match fs::blockdev::list_block_devices().await {
+        Ok(devices) => Ok(Json(devices)),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/storage/io", tag = "storage", responses((status = 200, body = [fs::diskstats::DiskIoRate])))]
+async fn get_disk_io() -> Json<Vec<fs::diskstats::DiskIoRate>> {
+    Json(sampler::latest_disk_io())
+}
+
+#[utoipa::path(get, path = "/api/v2/storage/io/history", tag = "storage", responses((status = 200, body = [sampler::Sample<Vec<fs::diskstats::DiskIoRate>>])))]
+async fn get_disk_io_history() -> Json<Vec<sampler::Sample<Vec<fs::diskstats::DiskIoRate>>>> {
+    Json(sampler::disk_io_history())
+}
+
+#[utoipa::path(get, path = "/api/v2/network/interfaces", tag = "network", responses((status = 200, body = [network::InterfaceInfo]), (status = 500, description = "Falha ao coletar os dados")))]
+async fn list_network_interfaces() -> axum::response::Result<Json<Vec<network::InterfaceInfo>>, axum::http::StatusCode> {
+    match network::list_interfaces(sampler::latest_network()).await {
+        Ok(interfaces) => Ok(Json(interfaces)),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/network/connections", tag = "network", params(network::connections::ConnectionQuery), responses((status = 200, body = [network::connections::ConnectionInfo]), (status = 500, description = "Falha ao coletar os dados"), (status = 501, description = "Plataforma sem /proc (disponível só no Linux)")))]
+async fn list_network_connections(Query(query): Query<network::connections::ConnectionQuery>) -> axum::response::Result<Json<Vec<network::connections::ConnectionInfo>>, axum::http::StatusCode> {
+    match network::connections::list_connections(query).await {
+        Ok(connections) => Ok(Json(connections)),
+        Err(e) => Err(io_error_status(&e)),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/network/listeners", tag = "network", responses((status = 200, body = [network::listeners::ListenerInfo]), (status = 500, description = "Falha ao coletar os dados")))]
+async fn list_network_listeners() -> axum::response::Result<Json<Vec<network::listeners::ListenerInfo>>, axum::http::StatusCode> {
+    match network::listeners::list_listeners().await {
+        Ok(listeners) => Ok(Json(listeners)),
+        Err(_) => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/network/listeners/changes", tag = "network", responses((status = 200, body = [sampler::Sample<network::listeners::ListenerChange>])))]
+async fn get_listener_changes() -> Json<Vec<sampler::Sample<network::listeners::ListenerChange>>> {
+    Json(sampler::listener_changes())
+}
+
+#[utoipa::path(get, path = "/api/v2/network/interfaces/history", tag = "network", responses((status = 200, body = [sampler::Sample<Vec<network::InterfaceRate>>])))]
+async fn get_network_history() -> Json<Vec<sampler::Sample<Vec<network::InterfaceRate>>>> {
+    Json(sampler::network_history())
+}
+
+#[utoipa::path(get, path = "/healthz", tag = "health", security(()), responses((status = 200, description = "Processo vivo", body = String)))]
+async fn healthz() -> &'static str {
+    "ok"
+}
+
+#[utoipa::path(get, path = "/readyz", tag = "health", security(()), responses((status = 200, body = health::Readiness), (status = 503, description = "Sampler sem tick recente", body = health::Readiness)))]
+async fn readyz(State(max_intervals): State<u32>) -> (axum::http::StatusCode, Json<health::Readiness>) {
+    let readiness = health::readiness(max_intervals);
+    let status = if readiness.ready { axum::http::StatusCode::OK } else { axum::http::StatusCode::SERVICE_UNAVAILABLE };
+    (status, Json(readiness))
+}
+
+#[utoipa::path(get, path = "/api/v2/self", tag = "health", responses((status = 200, body = health::SelfInfo)))]
+async fn get_self() -> Json<health::SelfInfo> {
+    Json(health::self_info())
+}
+
+#[utoipa::path(get, path = "/api/v2/audit", tag = "audit", params(audit::AuditQuery), responses((status = 200, body = [audit::AuditEntry]), (status = 403, description = "Requer papel admin"), (status = 500, description = "Falha ao coletar os dados")))]
+async fn get_audit(
+    Extension(log): Extension<Arc<audit::AuditLog>>,
+    Query(query): Query<audit::AuditQuery>,
+) -> axum::response::Result<Json<Vec<audit::AuditEntry>>, axum::http::StatusCode> {
+    match tokio::task::spawn_blocking(move || log.query(&query)).await {
+        Ok(Ok(entries)) => Ok(Json(entries)),
+        _ => Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(get, path = "/api/v2/log/level", tag = "log", responses((status = 200, body = logging::LogLevel), (status = 403, description = "Requer papel admin")))]
+async fn get_log_level(Extension(handle): Extension<logging::LevelHandle>) -> Json<logging::LogLevel> {
+    Json(handle.get())
+}
+
+#[utoipa::path(put, path = "/api/v2/log/level", tag = "log", request_body = logging::LogLevel, responses((status = 200, body = logging::LogLevel), (status = 400, description = "Filtro de log inválido"), (status = 403, description = "Requer papel admin")))]
+async fn set_log_level(
+    Extension(handle): Extension<logging::LevelHandle>,
+    Json(request): Json<logging::LogLevel>,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+    let previous = handle.get().level;
+    // O nível pedido e o anterior entram na auditoria, inclusive quando a troca é recusada
+    let details = audit::AuditDetails::from([("level", request.level.as_str()), ("previous_level", previous.as_str())]);
+    match handle.set(&request.level) {
+        Ok(level) => {
+            tracing::info!(level = %level.level, previous = %previous, "nível de log alterado");
+            (Extension(details), Json(level)).into_response()
+        }
+        Err(e) => (axum::http::StatusCode::BAD_REQUEST, Extension(details), format!("{:#}", e)).into_response(),
+    }
+}
+
+// Especificação OpenAPI gerada a partir dos handlers e tipos de resposta
+#[derive(utoipa::OpenApi)]
+#[openapi(
+    info(title = "OS Dashboard API", license(name = "MIT")),
+    paths(
+        list_processes, get_process, list_process_handles,
+        get_system_info, get_system_load, get_system_load_history, get_memory_details, get_swap_history, get_interrupts,
+        get_cpu_topology, get_cpu_details, get_cpu_times_history,
+        get_sensors, get_sensors_history,
+        list_partitions, tail_file, follow_file,
+        list_block_devices, get_disk_io, get_disk_io_history,
+        list_network_interfaces, get_network_history, list_network_connections, list_network_listeners, get_listener_changes,
+        get_audit,
+        healthz, readyz, get_self,
+        get_log_level, set_log_level,
+    ),
+    modifiers(&BearerAuth),
+)]
+struct ApiDoc;
+
+struct BearerAuth;
+
+impl utoipa::Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme};
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme("bearer", SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()));
+        // Só é exigido quando auth.enabled = true
+        openapi.security = Some(vec![SecurityRequirement::new("bearer", Vec::<String>::new())]);
+    }
+}
+
+async fn get_openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(<ApiDoc as utoipa::OpenApi>::openapi())
+}
+
+// Compara a especificação atual com o snapshot versionado; usado no CI para detectar mudanças na API
+fn check_openapi(snapshot: &std::path::Path) -> Result<(), anyhow::Error> {
+    let expected: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(snapshot)?)?;
+    let current = serde_json::to_value(<ApiDoc as utoipa::OpenApi>::openapi())?;
+    if expected != current {
+        anyhow::bail!("{} está desatualizado; regenere com `cargo run -- --print-openapi > {}`", snapshot.display(), snapshot.display());
+    }
+    Ok(())
+}
+
+// Rotas da API na forma sem versão (/api/...), tiradas da especificação; servem para validar as chaves de [limits]
+fn api_routes() -> std::collections::BTreeSet<String> {
+    let openapi = <ApiDoc as utoipa::OpenApi>::openapi();
+    openapi.paths.paths.keys().filter(|p| p.starts_with("/api/")).map(|p| api::canonical_route(p).into_owned()).chain(["/api/openapi.json".to_string()]).collect()
+}
+
+// Rotas com o mesmo formato em todas as versões, relativas ao prefixo da versão
+fn shared_routes(enabled: config::SubsystemsConfig, audit_log: Option<&Arc<audit::AuditLog>>, tail_roots: &Arc<fs::tail::TailRoots>) -> Router {
+    let mut app = Router::new();
+    // Rotas que expõem conteúdo de arquivos ou permitem ações exigem o papel admin
+    let mut admin = Router::new();
+    if enabled.processes {
+        app = app.route("/processes/{pid}/handles", get(list_process_handles));
+    }
+    if enabled.system {
+        app = app
+            .route("/system/load", get(get_system_load))
+            .route("/system/load/history", get(get_system_load_history))
+            .route("/system/memory", get(get_memory_details))
+            .route("/system/interrupts", get(get_interrupts))
+            .route("/system/memory/swap/history", get(get_swap_history));
+    }
+    if enabled.cpu {
+        app = app
+            .route("/cpu", get(get_cpu_topology))
+            .route("/system/cpu", get(get_cpu_details))
+            .route("/system/cpu/history", get(get_cpu_times_history));
+    }
+    if enabled.sensors {
+        app = app
+            .route("/sensors", get(get_sensors))
+            .route("/sensors/history", get(get_sensors_history));
+    }
+    if enabled.filesystem {
+        app = app.route("/filesystem/partitions", get(list_partitions));
+        admin = admin
+            .route("/filesystem/tail", get(tail_file))
+            .route("/filesystem/tail/follow", get(follow_file))
+            .layer(Extension(tail_roots.clone()));
+    }
+    if enabled.storage {
+        app = app
+            .route("/storage/devices", get(list_block_devices))
+            .route("/storage/io", get(get_disk_io))
+            .route("/storage/io/history", get(get_disk_io_history));
+    }
+    if enabled.network {
+        app = app
+            .route("/network/interfaces", get(list_network_interfaces))
+            .route("/network/interfaces/history", get(get_network_history))
+            .route("/network/connections", get(list_network_connections))
+            .route("/network/listeners", get(list_network_listeners))
+            .route("/network/listeners/changes", get(get_listener_changes));
+    }
+    if let Some(log) = audit_log {
+        admin = admin.route("/audit", get(get_audit)).layer(Extension(log.clone()));
+    }
+    app.merge(admin.route_layer(axum::middleware::from_fn_with_state(auth::Role::Admin, auth::require_role)))
+}
+
+// Monta o Router apenas com os subsistemas habilitados na configuração
+fn build_router(config: &config::Config, audit_log: Option<Arc<audit::AuditLog>>, log_level: logging::LevelHandle) -> Router {
+    let enabled = config.subsystems;
+    let tail_roots = Arc::new(fs::tail::TailRoots::from_config(&config.filesystem.tail_roots));
+    // Rotas novas entram só na v2; a v1 fica congelada até o sunset
+    let mut v2 = shared_routes(enabled, audit_log.as_ref(), &tail_roots)
+        .route("/self", get(get_self))
+        .merge(Router::new().route("/log/level", get(get_log_level).put(set_log_level)).route_layer(axum::middleware::from_fn_with_state(auth::Role::Admin, auth::require_role)));
+    let mut v1 = shared_routes(enabled, audit_log.as_ref(), &tail_roots);
+    if enabled.processes {
+        v2 = v2.route("/processes", get(list_processes)).route("/processes/{pid}", get(get_process));
+        v1 = v1.route("/processes", get(list_processes_v1));
+    }
+    if enabled.system {
+        v2 = v2.route("/system", get(get_system_info));
+        v1 = v1.route("/system", get(get_system_info_v1));
+    }
+    // validate() já conferiu as datas de depreciação e sunset
+    let deprecation = api::Deprecation::new(&config.api.v1_deprecated, &config.api.v1_sunset).expect("api.v1_deprecated e api.v1_sunset validados");
+    let v1 = v1.layer(axum::middleware::from_fn_with_state(deprecation, api::deprecated));
+    // /api sem versão continua respondendo no formato da v1
     let app = Router::new()
-        .route("/api/processes", get(list_processes))
-        .route("/api/system", get(get_system_info))
-        .route("/api/filesystem/partitions", get(list_partitions))
-        .route("/api/processes/{pid}/handles", get(list_process_handles));
-    let addr: std::net::SocketAddr = "[::]:3001".parse().unwrap();
-    println!("API rodando em http://localhost:3001/api/");
-    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app.into_make_service()).await.unwrap();
+        .route("/api/openapi.json", get(get_openapi))
+        .nest("/api/v2", v2)
+        .nest("/api/v1", v1.clone())
+        .nest("/api", v1);
+    // validate() já compilou os padrões de redação
+    let redactor = process::redact::Redactor::from_config(&config.redaction).unwrap_or_default();
+    let app = app.layer(Extension(Arc::new(redactor))).layer(Extension(log_level));
+    let limits = config::limits::Limits::from_config(&config.limits);
+    let mut app = app
+        .route_layer(axum::middleware::from_fn_with_state(limits, config::limits::enforce))
+        .route_layer(axum::middleware::from_fn(health::track))
+        .route_layer(axum::middleware::from_fn_with_state(auth::Authenticator::from_config(&config.auth), auth::authenticate));
+    // A auditoria fica por fora da autenticação e dos limites para registrar também os 401 e as recusas por limite
+    if let Some(log) = audit_log {
+        app = app.route_layer(axum::middleware::from_fn_with_state(log, audit::record));
+    }
+    let app = app.layer(axum::middleware::from_fn(logging::trace_request));
+    // Sondas de liveness/readiness ficam fora da autenticação, como a interface
+    let app = app.merge(
+        Router::new()
+            .route("/healthz", get(healthz))
+            .route("/readyz", get(readyz))
+            .with_state(config.sampler.ready_max_intervals),
+    );
+    // A interface fica fora da autenticação: o navegador carrega a página antes de ter um token
+    match ui::UiSource::resolve(config.ui.dir.as_deref()).filter(|_| config.ui.enabled) {
+        Some(source) => {
+            let source = Arc::new(source);
+            app.fallback(move |request: axum::extract::Request| ui::serve(source.clone(), request))
+        }
+        None => app,
+    }
+}
+
+// --- Função principal: inicializa o servidor HTTP ---
+// O runtime só é criado depois da configuração: variáveis de ambiente não podem ser alteradas com outras threads rodando
+fn main() {
+    let cli = config::Cli::parse();
+    if let Some(token) = &cli.hash_token {
+        println!("{}", auth::hash_token(token));
+        return;
+    }
+    if cli.print_openapi {
+        match <ApiDoc as utoipa::OpenApi>::openapi().to_pretty_json() {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Erro ao gerar a especificação OpenAPI: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    if let Some(snapshot) = &cli.check_openapi {
+        if let Err(e) = check_openapi(snapshot) {
+            eprintln!("{:#}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+    let config = match config::load(&cli) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Erro de configuração: {:#}", e);
+            std::process::exit(2);
+        }
+    };
+    if cli.print_config {
+        match config.to_toml() {
+            Ok(toml) => print!("{}", toml),
+            Err(e) => {
+                eprintln!("Erro ao serializar a configuração: {:#}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    let log_level = match logging::init(&config.log) {
+        Ok(handle) => handle,
+        Err(e) => {
+            eprintln!("Erro ao iniciar os logs: {:#}", e);
+            std::process::exit(1);
+        }
+    };
+    if config.server.backtrace {
+        std::env::set_var("RUST_BACKTRACE", "1");
+    }
+    let runtime = match tokio::runtime::Builder::new_multi_thread().enable_all().build() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            tracing::error!(error = %e, "não foi possível iniciar o runtime");
+            std::process::exit(1);
+        }
+    };
+    runtime.block_on(serve(config, log_level));
+}
+
+async fn serve(config: config::Config, log_level: logging::LevelHandle) {
+    let rustls = match &config.server.tls {
+        Some(tls_config) => match tls::load(tls_config) {
+            Ok(rustls) => {
+                tls::spawn_reloader(tls_config.clone(), rustls.clone());
+                Some(rustls)
+            }
+            Err(e) => {
+                tracing::error!(error = format!("{:#}", e), "erro de configuração TLS");
+                std::process::exit(2);
+            }
+        },
+        None => None,
+    };
+    sampler::spawn(config.sampler.interval(), config.sampler.retention, config.subsystems);
+    let audit_log = match config.audit.enabled.then(|| audit::AuditLog::open(&config.audit)).transpose() {
+        Ok(log) => log.map(Arc::new),
+        Err(e) => {
+            tracing::error!(error = format!("{:#}", e), "erro de configuração da auditoria");
+            std::process::exit(2);
+        }
+    };
+    let app = build_router(&config, audit_log, log_level);
+    // validate() já garantiu que os endereços são válidos
+    let addrs = config.listen_addrs().unwrap_or_default();
+    if !config.auth.enabled && addrs.iter().any(|a| !a.ip().is_loopback()) {
+        tracing::warn!("autenticação desligada e API acessível fora do loopback; configure [auth] para restringir o acesso");
+    }
+    let scheme = if rustls.is_some() { "https" } else { "http" };
+    let mut servers = tokio::task::JoinSet::new();
+    for addr in addrs {
+        let listener = match tokio::net::TcpListener::bind(addr).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                tracing::error!(%addr, error = %e, "não foi possível escutar");
+                std::process::exit(1);
+            }
+        };
+        tracing::info!(url = format!("{}://{}/api/", scheme, addr), "API rodando");
+        if let Some(source) = ui::UiSource::resolve(config.ui.dir.as_deref()).filter(|_| config.ui.enabled) {
+            tracing::info!(url = format!("{}://{}/", scheme, addr), source = %source.describe(), "dashboard disponível");
+        }
+        let app = app.clone();
+        match &rustls {
+            Some(rustls) => {
+                let server = listener.into_std().and_then(|l| axum_server::from_tcp_rustls(l, rustls.clone()));
+                servers.spawn(async move { server?.serve(app.into_make_service()).await });
+            }
+            None => {
+                servers.spawn(async move { axum::serve(listener, app.into_make_service()).await });
+            }
+        }
+    }
+    while let Some(result) = servers.join_next().await {
+        if let Ok(Err(e)) = result {
+            tracing::error!(error = %e, "erro no servidor");
+        }
+    }
 }
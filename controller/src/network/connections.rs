@@ -0,0 +1,412 @@
+// Módulo para a tabela de conexões TCP/UDP/Unix (/proc/net) com o processo dono de cada socket
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+use tokio::task;
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct ConnectionInfo {
+    pub protocol: String,
+    pub state: String,
+    pub listening: bool,
+    pub local_address: Option<String>,
+    pub local_port: Option<u16>,
+    pub remote_address: Option<String>,
+    pub remote_port: Option<u16>,
+    pub path: Option<String>,
+    pub tx_queue: u64,
+    pub rx_queue: u64,
+    pub uid: Option<u32>,
+    pub inode: u64,
+    pub pid: Option<u32>,
+    pub process_name: Option<String>,
+}
+
+#[derive(Deserialize, IntoParams, Debug, Clone, Default)]
+#[into_params(parameter_in = Query)]
+pub struct ConnectionQuery {
+    pub listening: Option<bool>,
+    pub port: Option<u16>,
+    pub state: Option<String>,
+    pub protocol: Option<String>,
+}
+
+impl ConnectionQuery {
+    fn matches(&self, c: &ConnectionInfo) -> bool {
+        if self.listening == Some(true) && !c.listening {
+            return false;
+        }
+        if let Some(port) = self.port {
+            if c.local_port != Some(port) && c.remote_port != Some(port) {
+                return false;
+            }
+        }
+        if let Some(state) = &self.state {
+            if !c.state.eq_ignore_ascii_case(state) {
+                return false;
+            }
+        }
+        if let Some(protocol) = &self.protocol {
+            // "tcp" também seleciona "tcp6"
+            if !c.protocol.starts_with(&protocol.to_lowercase()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn tcp_state(code: u8) -> &'static str {
+    match code {
+        0x01 => "ESTABLISHED",
+        0x02 => "SYN_SENT",
+        0x03 => "SYN_RECV",
+        0x04 => "FIN_WAIT1",
+        0x05 => "FIN_WAIT2",
+        0x06 => "TIME_WAIT",
+        0x07 => "CLOSE",
+        0x08 => "CLOSE_WAIT",
+        0x09 => "LAST_ACK",
+        0x0A => "LISTEN",
+        0x0B => "CLOSING",
+        0x0C => "NEW_SYN_RECV",
+        _ => "UNKNOWN",
+    }
+}
+
+fn udp_state(code: u8) -> &'static str {
+    match code {
+        0x01 => "ESTABLISHED",
+        0x07 => "UNCONN",
+        _ => "UNKNOWN",
+    }
+}
+
+fn unix_state(code: u8) -> &'static str {
+    match code {
+        0x01 => "UNCONNECTED",
+        0x02 => "CONNECTING",
+        0x03 => "CONNECTED",
+        0x04 => "DISCONNECTING",
+        _ => "UNKNOWN",
+    }
+}
+
+// O kernel imprime cada palavra de 32 bits na ordem de bytes do host (little-endian no x86)
+fn parse_address(hex: &str) -> Option<(String, u16)> {
+    let (addr, port) = hex.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let words: Vec<u32> = (0..addr.len() / 8)
+        .map(|i| u32::from_str_radix(&addr[i * 8..i * 8 + 8], 16))
+        .collect::<Result<_, _>>()
+        .ok()?;
+    let ip = match words.len() {
+        1 => Ipv4Addr::from(words[0].to_ne_bytes()).to_string(),
+        4 => {
+            let mut bytes = [0u8; 16];
+            for (i, w) in words.iter().enumerate() {
+                bytes[i * 4..i * 4 + 4].copy_from_slice(&w.to_ne_bytes());
+            }
+            let v6 = Ipv6Addr::from(bytes);
+            // Endereços IPv4 mapeados (::ffff:a.b.c.d) são mostrados como IPv4
+            v6.to_ipv4_mapped().map(|v4| v4.to_string()).unwrap_or_else(|| v6.to_string())
+        }
+        _ => return None,
+    };
+    Some((ip, port))
+}
+
+fn is_unspecified(address: &str) -> bool {
+    address.parse::<std::net::IpAddr>().is_ok_and(|ip| ip.is_unspecified())
+}
+
+fn parse_queue(field: &str) -> (u64, u64) {
+    match field.split_once(':') {
+        Some((tx, rx)) => (u64::from_str_radix(tx, 16).unwrap_or(0), u64::from_str_radix(rx, 16).unwrap_or(0)),
+        None => (0, 0),
+    }
+}
+
+// Função para interpretar /proc/net/{tcp,tcp6,udp,udp6}
+pub fn parse_inet(content: &str, protocol: &str) -> Vec<ConnectionInfo> {
+    let is_tcp = protocol.starts_with("tcp");
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let f: Vec<&str> = line.split_whitespace().collect();
+            if f.len() < 10 {
+                return None;
+            }
+            let (local_address, local_port) = parse_address(f[1])?;
+            let (remote_address, remote_port) = parse_address(f[2])?;
+            let code = u8::from_str_radix(f[3], 16).ok()?;
+            let state = if is_tcp { tcp_state(code) } else { udp_state(code) };
+            let (tx_queue, rx_queue) = parse_queue(f[4]);
+            Some(ConnectionInfo {
+                protocol: protocol.to_string(),
+                state: state.to_string(),
+                // UDP não conectado (sem connect(), endereço remoto todo zero) é o socket que recebe de qualquer origem
+                listening: state == "LISTEN" || (state == "UNCONN" && remote_port == 0 && is_unspecified(&remote_address)),
+                local_address: Some(local_address),
+                local_port: Some(local_port),
+                remote_address: Some(remote_address),
+                remote_port: Some(remote_port),
+                path: None,
+                tx_queue,
+                rx_queue,
+                uid: f[7].parse().ok(),
+                inode: f[9].parse().ok()?,
+                pid: None,
+                process_name: None,
+            })
+        })
+        .collect()
+}
+
+// Função para interpretar /proc/net/unix
+pub fn parse_unix(content: &str) -> Vec<ConnectionInfo> {
+    const SO_ACCEPTCON: u32 = 0x10000;
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let f: Vec<&str> = line.split_whitespace().collect();
+            if f.len() < 7 {
+                return None;
+            }
+            let flags = u32::from_str_radix(f[3], 16).ok()?;
+            let kind = match f[4] {
+                "0001" => "unix_stream",
+                "0002" => "unix_dgram",
+                "0005" => "unix_seqpacket",
+                _ => "unix",
+            };
+            let listening = flags & SO_ACCEPTCON != 0;
+            let state = if listening { "LISTEN" } else { unix_state(u8::from_str_radix(f[5], 16).ok()?) };
+            Some(ConnectionInfo {
+                protocol: kind.to_string(),
+                state: state.to_string(),
+                listening,
+                local_address: None,
+                local_port: None,
+                remote_address: None,
+                remote_port: None,
+                // O caminho vai até o fim da linha e pode conter espaços
+                path: f.get(7).map(|p| line[p.as_ptr() as usize - line.as_ptr() as usize..].trim_end().to_string()),
+                tx_queue: 0,
+                rx_queue: 0,
+                uid: None,
+                inode: f[6].parse().ok()?,
+                pid: None,
+                process_name: None,
+            })
+        })
+        .collect()
+}
+
+// Função para mapear inode de socket -> pid percorrendo /proc/<pid>/fd
+pub fn socket_owners(proc_root: &Path) -> HashMap<u64, u32> {
+    let mut owners = HashMap::new();
+    let Ok(entries) = std::fs::read_dir(proc_root) else { return owners };
+    for entry in entries.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|n| n.parse::<u32>().ok()) else { continue };
+        // Sem permissão para ler os fds de processos de outros usuários
+        let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else { continue };
+        for fd in fds.flatten() {
+            if let Ok(target) = std::fs::read_link(fd.path()) {
+                let target = target.to_string_lossy();
+                if let Some(inode) = target.strip_prefix("socket:[").and_then(|t| t.strip_suffix(']')).and_then(|t| t.parse().ok()) {
+                    owners.entry(inode).or_insert(pid);
+                }
+            }
+        }
+    }
+    owners
+}
+
+pub fn process_name(proc_root: &Path, pid: u32) -> Option<String> {
+    std::fs::read_to_string(proc_root.join(pid.to_string()).join("comm")).ok().map(|s| s.trim().to_string())
+}
+
+// Função síncrona que monta a tabela completa; usada também pelo inventário de portas
+pub fn collect_connections(proc_root: &Path) -> Vec<ConnectionInfo> {
+    let mut connections = Vec::new();
+    for protocol in ["tcp", "tcp6", "udp", "udp6"] {
+        if let Ok(content) = std::fs::read_to_string(proc_root.join("net").join(protocol)) {
+            connections.extend(parse_inet(&content, protocol));
+        }
+    }
+    if let Ok(content) = std::fs::read_to_string(proc_root.join("net/unix")) {
+        connections.extend(parse_unix(&content));
+    }
+    let owners = socket_owners(proc_root);
+    let mut names: HashMap<u32, Option<String>> = HashMap::new();
+    for c in connections.iter_mut() {
+        // Inode 0 indica socket sem dono (ex.: TIME_WAIT)
+        if c.inode == 0 {
+            continue;
+        }
+        if let Some(&pid) = owners.get(&c.inode) {
+            c.pid = Some(pid);
+            c.process_name = names.entry(pid).or_insert_with(|| process_name(proc_root, pid)).clone();
+        }
+    }
+    connections
+}
+
+// Função para listar conexões aplicando os filtros da query; só o Linux tem /proc/net, nas demais plataformas a
+// rota responde 501 em vez de uma lista vazia que pareceria "nenhuma conexão"
+pub async fn list_connections(query: ConnectionQuery) -> std::io::Result<Vec<ConnectionInfo>> {
+    if !cfg!(target_os = "linux") {
+        return Err(std::io::ErrorKind::Unsupported.into());
+    }
+    task::spawn_blocking(move || {
+        collect_connections(Path::new("/proc")).into_iter().filter(|c| query.matches(c)).collect()
+    }).await.map_err(std::io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TCP: &str = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n\
+           0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 5555 1 0000000000000000 100 0 0 10 0\n\
+           1: 0100007F:1F90 0100007F:C350 01 0000000A:00000002 00:00000000 00000000  1000        0 5556 1 0000000000000000 20 4 30 10 -1\n\
+           2: 0100007F:C34F 0100007F:1F90 06 00000000:00000000 03:00001770 00000000     0        0 0 3 0000000000000000\n";
+
+    const TCP6: &str = "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n\
+           0: 00000000000000000000000001000000:0016 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 6000 1 0000000000000000 100 0 0 10 0\n\
+           1: 0000000000000000FFFF00000100000A:01BB B80D0120000000000000000001000000:D431 01 00000000:00000000 00:00000000 00000000     0        0 6001 1 0000000000000000 20 4 30 10 -1\n";
+
+    const UDP: &str = "   sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops\n\
+          0: 00000000:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 7000 2 0000000000000000 0\n\
+          1: 0100007F:E1D2 0101A8C0:0035 01 00000000:00000000 00:00000000 00000000   101        0 7001 2 0000000000000000 0\n";
+
+    const UNIX: &str = "Num       RefCount Protocol Flags    Type St Inode Path\n\
+        0000000000000000: 00000002 00000000 00010000 0001 01 8000 /run/app.sock\n\
+        0000000000000000: 00000003 00000000 00000000 0001 03 8001\n\
+        0000000000000000: 00000002 00000000 00000000 0002 01 8002 @abstract\n\
+        0000000000000000: 00000002 00000000 00000000 0005 02 8003\n";
+
+    // O kernel imprime as palavras de 32 bits na ordem do host; os fixtures estão em little-endian
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn decodes_ipv4_and_ipv6_words() {
+        assert_eq!(parse_address("0100007F:1F90"), Some(("127.0.0.1".to_string(), 8080)));
+        assert_eq!(parse_address("0101A8C0:0035"), Some(("192.168.1.1".to_string(), 53)));
+        assert_eq!(parse_address("00000000000000000000000001000000:0016"), Some(("::1".to_string(), 22)));
+        assert_eq!(parse_address("B80D0120000000000000000001000000:D431"), Some(("2001:db8::1".to_string(), 54321)));
+        // ::ffff:10.0.0.1 aparece como IPv4
+        assert_eq!(parse_address("0000000000000000FFFF00000100000A:01BB"), Some(("10.0.0.1".to_string(), 443)));
+        for bad in ["0100007F", "0100007F:XYZ", "0100007G:0050", "01000:0050", "0000000000000000:0050"] {
+            assert_eq!(parse_address(bad), None, "{}", bad);
+        }
+    }
+
+    #[test]
+    fn maps_state_codes() {
+        assert_eq!([0x01, 0x06, 0x0A, 0x0C, 0x42].map(tcp_state), ["ESTABLISHED", "TIME_WAIT", "LISTEN", "NEW_SYN_RECV", "UNKNOWN"]);
+        assert_eq!([0x01, 0x07, 0x0A].map(udp_state), ["ESTABLISHED", "UNCONN", "UNKNOWN"]);
+        assert_eq!([0x01, 0x03, 0x04, 0x09].map(unix_state), ["UNCONNECTED", "CONNECTED", "DISCONNECTING", "UNKNOWN"]);
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn parses_inet_tables() {
+        let tcp = parse_inet(TCP, "tcp");
+        let summary: Vec<_> = tcp.iter().map(|c| (c.state.as_str(), c.listening, c.local_port, c.remote_port, c.inode)).collect();
+        assert_eq!(
+            summary,
+            vec![("LISTEN", true, Some(8080), Some(0), 5555), ("ESTABLISHED", false, Some(8080), Some(50000), 5556), ("TIME_WAIT", false, Some(49999), Some(8080), 0)]
+        );
+        assert_eq!((tcp[1].tx_queue, tcp[1].rx_queue, tcp[1].uid), (10, 2, Some(1000)));
+
+        let tcp6 = parse_inet(TCP6, "tcp6");
+        assert_eq!((tcp6[0].local_address.as_deref(), tcp6[0].state.as_str()), (Some("::1"), "LISTEN"));
+        assert_eq!(tcp6[1].remote_address.as_deref(), Some("2001:db8::1"));
+
+        // UDP sem connect() recebe de qualquer origem; o conectado não conta como escuta
+        let udp = parse_inet(UDP, "udp");
+        assert_eq!((udp[0].state.as_str(), udp[0].listening), ("UNCONN", true));
+        assert_eq!((udp[1].state.as_str(), udp[1].listening, udp[1].remote_address.as_deref()), ("ESTABLISHED", false, Some("192.168.1.1")));
+    }
+
+    #[test]
+    fn parses_unix_types_and_states() {
+        let sockets = parse_unix(UNIX);
+        let summary: Vec<_> = sockets.iter().map(|c| (c.protocol.as_str(), c.state.as_str(), c.listening, c.path.as_deref())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("unix_stream", "LISTEN", true, Some("/run/app.sock")),
+                ("unix_stream", "CONNECTED", false, None),
+                ("unix_dgram", "UNCONNECTED", false, Some("@abstract")),
+                ("unix_seqpacket", "CONNECTING", false, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn unix_path_keeps_spaces() {
+        let content = "Num       RefCount Protocol Flags    Type St Inode Path\n\
+            0000000000000000: 00000002 00000000 00010000 0001 01 2345 /run/user/1000/my app  dir/sock\n\
+            0000000000000000: 00000002 00000000 00000000 0002 01 2346\n";
+        let sockets = parse_unix(content);
+        assert_eq!(sockets[0].path.as_deref(), Some("/run/user/1000/my app  dir/sock"));
+        assert!(sockets[0].listening);
+        assert_eq!(sockets[1].path, None);
+    }
+
+    #[cfg(all(unix, target_endian = "little"))]
+    #[test]
+    fn matches_socket_inodes_to_processes() {
+        use crate::testutil::TempDir;
+        use std::os::unix::fs::symlink;
+        let root = TempDir::with_files(
+            "connections",
+            &[
+                ("net/tcp", TCP),
+                ("net/tcp6", TCP6),
+                ("net/udp", UDP),
+                ("net/unix", UNIX),
+                ("1234/comm", "nginx\n"),
+                ("42/comm", "dnsmasq\n"),
+                // Processo sem fd legível (outro usuário) e entradas não numéricas são ignorados
+                ("77/comm", "other\n"),
+                ("sys/placeholder", ""),
+            ],
+        );
+        for (fd, target) in [
+            ("1234/fd/3", "socket:[5555]"),
+            ("1234/fd/4", "socket:[5556]"),
+            ("1234/fd/5", "/var/log/nginx/access.log"),
+            ("42/fd/0", "/dev/null"),
+            ("42/fd/6", "socket:[7000]"),
+            ("42/fd/7", "socket:[8000]"),
+        ] {
+            let link = root.path().join(fd);
+            std::fs::create_dir_all(link.parent().unwrap()).unwrap();
+            symlink(target, link).unwrap();
+        }
+
+        let owners = socket_owners(root.path());
+        assert_eq!(owners, HashMap::from([(5555, 1234), (5556, 1234), (7000, 42), (8000, 42)]));
+
+        let connections = collect_connections(root.path());
+        assert_eq!(connections.len(), 11);
+        let owner = |inode: u64| connections.iter().find(|c| c.inode == inode).map(|c| (c.pid, c.process_name.as_deref()));
+        assert_eq!(owner(5556), Some((Some(1234), Some("nginx"))));
+        assert_eq!(owner(7000), Some((Some(42), Some("dnsmasq"))));
+        assert_eq!(owner(8000), Some((Some(42), Some("dnsmasq"))));
+        // Sem dono conhecido e TIME_WAIT (inode 0) ficam sem processo
+        assert_eq!(owner(6000), Some((None, None)));
+        assert_eq!(owner(0), Some((None, None)));
+
+        let query = ConnectionQuery { listening: Some(true), protocol: Some("TCP".to_string()), ..Default::default() };
+        let listening: Vec<_> = connections.iter().filter(|c| query.matches(c)).map(|c| (c.protocol.as_str(), c.local_port)).collect();
+        assert_eq!(listening, vec![("tcp", Some(8080)), ("tcp6", Some(22))]);
+    }
+}
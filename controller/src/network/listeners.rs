@@ -0,0 +1,250 @@
+// Módulo para o inventário de portas em escuta ("serviços expostos") e detecção de mudanças
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::task;
+
+use super::connections::{parse_inet, socket_owners, ConnectionInfo};
+
+#[derive(Serialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BindScope {
+    AllInterfaces,
+    Loopback,
+    Specific,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct ListenerInfo {
+    pub protocol: String,
+    pub address: String,
+    pub port: u16,
+    pub scope: BindScope,
+    pub inode: u64,
+    pub uid: Option<u32>,
+    pub username: Option<String>,
+    pub pid: Option<u32>,
+    pub process_name: Option<String>,
+    pub exe_path: Option<String>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ListenerChangeKind {
+    Appeared,
+    Disappeared,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct ListenerChange {
+    pub kind: ListenerChangeKind,
+    pub listener: ListenerInfo,
+}
+
+// Inclui o inode: um socket recriado na mesma porta conta como mudança
+type ListenerKey = (String, String, u16, u64);
+
+fn scope_of(address: &str) -> BindScope {
+    match address.parse::<std::net::IpAddr>() {
+        Ok(ip) if ip.is_unspecified() => BindScope::AllInterfaces,
+        Ok(ip) if ip.is_loopback() => BindScope::Loopback,
+        _ => BindScope::Specific,
+    }
+}
+
+// Helper para resolver o nome de usuário a partir do uid
+#[cfg(unix)]
+fn username_for_uid(uid: u32) -> Option<String> {
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut buf = vec![0 as libc::c_char; 4096];
+    let mut result: *mut libc::passwd = std::ptr::null_mut();
+    let rc = unsafe { libc::getpwuid_r(uid, &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+    if rc != 0 || result.is_null() {
+        return None;
+    }
+    Some(unsafe { std::ffi::CStr::from_ptr(pwd.pw_name) }.to_string_lossy().to_string())
+}
+
+#[cfg(not(unix))]
+fn username_for_uid(_uid: u32) -> Option<String> {
+    None
+}
+
+fn to_listener(c: ConnectionInfo) -> Option<ListenerInfo> {
+    let address = c.local_address?;
+    Some(ListenerInfo {
+        protocol: c.protocol,
+        scope: scope_of(&address),
+        address,
+        port: c.local_port?,
+        inode: c.inode,
+        uid: c.uid,
+        username: None,
+        pid: None,
+        process_name: None,
+        exe_path: None,
+    })
+}
+
+// Lê apenas os sockets inet em escuta, sem resolver o processo dono (barato)
+fn read_listeners(proc_root: &Path) -> Vec<ListenerInfo> {
+    let mut listeners = Vec::new();
+    for protocol in ["tcp", "tcp6", "udp", "udp6"] {
+        if let Ok(content) = std::fs::read_to_string(proc_root.join("net").join(protocol)) {
+            // UDP conectado (par remoto definido) é cliente e fica de fora; ver parse_inet
+            listeners.extend(parse_inet(&content, protocol).into_iter().filter(|c| c.listening).filter_map(to_listener));
+        }
+    }
+    listeners
+}
+
+// Preenche processo, usuário e executável (percorre /proc/<pid>/fd)
+fn resolve_owners(proc_root: &Path, listeners: &mut [ListenerInfo]) {
+    let owners = socket_owners(proc_root);
+    for l in listeners.iter_mut() {
+        l.username = l.uid.and_then(username_for_uid);
+        if let Some(&pid) = owners.get(&l.inode) {
+            let pid_dir = proc_root.join(pid.to_string());
+            l.pid = Some(pid);
+            l.process_name = std::fs::read_to_string(pid_dir.join("comm")).ok().map(|s| s.trim().to_string());
+            l.exe_path = std::fs::read_link(pid_dir.join("exe")).ok().map(|p| p.to_string_lossy().to_string());
+        }
+    }
+}
+
+fn key_of(l: &ListenerInfo) -> ListenerKey {
+    (l.protocol.clone(), l.address.clone(), l.port, l.inode)
+}
+
+// Função para listar os serviços expostos com o processo responsável
+pub async fn list_listeners() -> Result<Vec<ListenerInfo>, anyhow::Error> {
+    let listeners = task::spawn_blocking(|| {
+        let proc_root = Path::new("/proc");
+        let mut listeners = read_listeners(proc_root);
+        resolve_owners(proc_root, &mut listeners);
+        listeners.sort_by(|a, b| (a.port, &a.protocol, &a.address).cmp(&(b.port, &b.protocol, &b.address)));
+        listeners
+    }).await?;
+    Ok(listeners)
+}
+
+// Guarda o inventário da última varredura; sockets novos são comparados por (protocolo, endereço, porta, inode)
+pub struct ListenerCollector {
+    proc_root: PathBuf,
+    previous: Option<HashMap<ListenerKey, ListenerInfo>>,
+}
+
+impl Default for ListenerCollector {
+    fn default() -> Self {
+        ListenerCollector::new("/proc")
+    }
+}
+
+impl ListenerCollector {
+    pub fn new(proc_root: impl Into<PathBuf>) -> Self {
+        ListenerCollector { proc_root: proc_root.into(), previous: None }
+    }
+
+    // Sockets que surgiram ou sumiram desde a varredura anterior; a primeira só monta o inventário
+    pub fn sample(&mut self) -> Vec<ListenerChange> {
+        let proc_root = self.proc_root.as_path();
+        let mut current = read_listeners(proc_root);
+        let Some(previous) = self.previous.as_mut() else {
+            resolve_owners(proc_root, &mut current);
+            self.previous = Some(current.into_iter().map(|l| (key_of(&l), l)).collect());
+            return Vec::new();
+        };
+        let current_keys: HashMap<ListenerKey, usize> = current.iter().enumerate().map(|(i, l)| (key_of(l), i)).collect();
+        let mut changes = Vec::new();
+        let gone: Vec<ListenerKey> = previous.keys().filter(|k| !current_keys.contains_key(*k)).cloned().collect();
+        for key in gone {
+            if let Some(listener) = previous.remove(&key) {
+                changes.push(ListenerChange { kind: ListenerChangeKind::Disappeared, listener });
+            }
+        }
+        let mut appeared: Vec<ListenerInfo> = current.drain(..).filter(|l| !previous.contains_key(&key_of(l))).collect();
+        // Só vale percorrer os fds de todos os processos quando há um socket novo
+        if !appeared.is_empty() {
+            resolve_owners(proc_root, &mut appeared);
+            for listener in appeared {
+                previous.insert(key_of(&listener), listener.clone());
+                changes.push(ListenerChange { kind: ListenerChangeKind::Appeared, listener });
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    const HEADER: &str = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops\n";
+
+    fn udp_line(local: &str, remote: &str, state: &str, inode: u64) -> String {
+        format!("   1: {} {} {} 00000000:00000000 00:00000000 00000000  1000        0 {} 2 0000000000000000 0\n", local, remote, state, inode)
+    }
+
+    #[cfg(unix)]
+    fn tcp_line(local: &str, state: &str, inode: u64) -> String {
+        format!("   0: {} 00000000:0000 {} 00000000:00000000 00:00000000 00000000     0        0 {} 1 0000000000000000 100 0 0 10 0\n", local, state, inode)
+    }
+
+    #[test]
+    fn connected_udp_is_not_a_listener() {
+        let root = TempDir::new("listeners-udp");
+        // 0035 = 53 e 9C40 = 40000 sem par remoto escutam; 0050 conectado a 8.8.8.8:53 (estado 01 ou 07) não
+        let udp = [
+            udp_line("00000000:0035", "00000000:0000", "07", 100),
+            udp_line("00000000:9C40", "00000000:0000", "07", 101),
+            udp_line("0100007F:0050", "08080808:0035", "01", 102),
+            udp_line("0100007F:0051", "08080808:0000", "07", 103),
+        ];
+        root.write("net/udp", format!("{}{}", HEADER, udp.concat()));
+        let listeners = read_listeners(root.path());
+        assert_eq!(listeners.iter().map(|l| (l.port, l.inode)).collect::<Vec<_>>(), vec![(53, 100), (40000, 101)]);
+    }
+
+    #[test]
+    fn recreated_socket_is_a_change() {
+        let root = TempDir::new("listeners-recreate");
+        root.write("net/udp", format!("{}{}", HEADER, udp_line("00000000:0035", "00000000:0000", "07", 100)));
+        let first = read_listeners(root.path());
+        root.write("net/udp", format!("{}{}", HEADER, udp_line("00000000:0035", "00000000:0000", "07", 200)));
+        let second = read_listeners(root.path());
+        assert_ne!(key_of(&first[0]), key_of(&second[0]));
+    }
+
+    // Os donos saem de links /proc/<pid>/fd/N -> "socket:[inode]"
+    #[cfg(unix)]
+    #[test]
+    fn sample_reports_appeared_and_disappeared_with_owner() {
+        let root = TempDir::new("listeners-sample");
+        root.write("net/tcp", format!("{}{}", HEADER, tcp_line("0100007F:1F90", "0A", 300)));
+        root.write("4242/comm", "server\n");
+        std::fs::create_dir_all(root.path().join("4242/fd")).unwrap();
+        std::os::unix::fs::symlink("socket:[301]", root.path().join("4242/fd/3")).unwrap();
+        std::os::unix::fs::symlink("/usr/bin/server", root.path().join("4242/exe")).unwrap();
+
+        let mut collector = ListenerCollector::new(root.path());
+        assert!(collector.sample().is_empty());
+        // Sem mudança na tabela, nada a relatar
+        assert!(collector.sample().is_empty());
+
+        // 8080 (inode 300) fecha e 0.0.0.0:443 (inode 301) abre
+        root.write("net/tcp", format!("{}{}", HEADER, tcp_line("00000000:01BB", "0A", 301)));
+        let changes = collector.sample();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].kind, ListenerChangeKind::Disappeared);
+        assert_eq!((changes[0].listener.port, changes[0].listener.scope), (8080, BindScope::Loopback));
+        let appeared = &changes[1];
+        assert_eq!(appeared.kind, ListenerChangeKind::Appeared);
+        assert_eq!((appeared.listener.port, appeared.listener.scope), (443, BindScope::AllInterfaces));
+        assert_eq!(appeared.listener.pid, Some(4242));
+        assert_eq!(appeared.listener.process_name.as_deref(), Some("server"));
+        assert_eq!(appeared.listener.exe_path.as_deref(), Some("/usr/bin/server"));
+        assert!(collector.sample().is_empty());
+    }
+}
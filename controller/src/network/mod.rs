@@ -0,0 +1,288 @@
+// Módulo para informações de rede (interfaces, contadores e taxas)
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::collections::HashMap;
+#[cfg(target_os = "linux")]
+use std::path::Path;
+use std::time::Instant;
+use tokio::task;
+#[cfg(target_os = "linux")]
+use crate::sysfs::{read_attr, read_flag};
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct InterfaceAddress {
+    pub family: String,
+    pub address: String,
+    pub prefix_len: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InterfaceCounters {
+    pub name: String,
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errors: u64,
+    pub tx_drops: u64,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct InterfaceRate {
+    pub name: String,
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    pub rx_packets_per_sec: f64,
+    pub tx_packets_per_sec: f64,
+    pub rx_errors_per_sec: f64,
+    pub tx_errors_per_sec: f64,
+    pub rx_drops_per_sec: f64,
+    pub tx_drops_per_sec: f64,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct InterfaceInfo {
+    pub name: String,
+    pub mac: Option<String>,
+    pub mtu: Option<u32>,
+    pub operstate: Option<String>,
+    pub carrier: Option<bool>,
+    pub speed_mbps: Option<u64>,
+    pub addresses: Vec<InterfaceAddress>,
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errors: u64,
+    pub tx_drops: u64,
+    pub rate: Option<InterfaceRate>,
+}
+
+#[cfg(target_os = "linux")]
+fn parse_line(line: &str) -> Option<InterfaceCounters> {
+    let (name, rest) = line.split_once(':')?;
+    let f: Vec<u64> = rest.split_whitespace().filter_map(|v| v.parse().ok()).collect();
+    if f.len() < 16 {
+        return None;
+    }
+    Some(InterfaceCounters {
+        name: name.trim().to_string(),
+        rx_bytes: f[0],
+        rx_packets: f[1],
+        rx_errors: f[2],
+        rx_drops: f[3],
+        tx_bytes: f[8],
+        tx_packets: f[9],
+        tx_errors: f[10],
+        tx_drops: f[11],
+    })
+}
+
+// Função para interpretar /proc/net/dev (as duas primeiras linhas são cabeçalho)
+#[cfg(target_os = "linux")]
+pub fn parse_net_dev(content: &str) -> Vec<InterfaceCounters> {
+    content.lines().skip(2).filter_map(parse_line).collect()
+}
+
+pub fn compute_rate(prev: &InterfaceCounters, cur: &InterfaceCounters, elapsed_secs: f64) -> InterfaceRate {
+    let elapsed = if elapsed_secs > 0.0 { elapsed_secs } else { 1.0 };
+    // Contadores podem zerar quando a interface é recriada
+    let r = |a: u64, b: u64| b.saturating_sub(a) as f64 / elapsed;
+    InterfaceRate {
+        name: cur.name.clone(),
+        rx_bytes_per_sec: r(prev.rx_bytes, cur.rx_bytes),
+        tx_bytes_per_sec: r(prev.tx_bytes, cur.tx_bytes),
+        rx_packets_per_sec: r(prev.rx_packets, cur.rx_packets),
+        tx_packets_per_sec: r(prev.tx_packets, cur.tx_packets),
+        rx_errors_per_sec: r(prev.rx_errors, cur.rx_errors),
+        tx_errors_per_sec: r(prev.tx_errors, cur.tx_errors),
+        rx_drops_per_sec: r(prev.rx_drops, cur.rx_drops),
+        tx_drops_per_sec: r(prev.tx_drops, cur.tx_drops),
+    }
+}
+
+// Helper para obter os endereços IPv4/IPv6 de cada interface via getifaddrs
+#[cfg(target_os = "linux")]
+fn interface_addresses() -> HashMap<String, Vec<InterfaceAddress>> {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    let mut map: HashMap<String, Vec<InterfaceAddress>> = HashMap::new();
+    unsafe {
+        let mut ifap: *mut libc::ifaddrs = std::ptr::null_mut();
+        if libc::getifaddrs(&mut ifap) != 0 {
+            return map;
+        }
+        let mut cur = ifap;
+        while !cur.is_null() {
+            let ifa = &*cur;
+            cur = ifa.ifa_next;
+            if ifa.ifa_addr.is_null() {
+                continue;
+            }
+            let name = std::ffi::CStr::from_ptr(ifa.ifa_name).to_string_lossy().to_string();
+            let entry = match (*ifa.ifa_addr).sa_family as i32 {
+                libc::AF_INET => {
+                    let addr = &*(ifa.ifa_addr as *const libc::sockaddr_in);
+                    let prefix_len = if ifa.ifa_netmask.is_null() { 0 } else { (*(ifa.ifa_netmask as *const libc::sockaddr_in)).sin_addr.s_addr.count_ones() };
+                    InterfaceAddress { family: "ipv4".to_string(), address: Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr)).to_string(), prefix_len }
+                }
+                libc::AF_INET6 => {
+                    let addr = &*(ifa.ifa_addr as *const libc::sockaddr_in6);
+                    let prefix_len = if ifa.ifa_netmask.is_null() {
+                        0
+                    } else {
+                        (*(ifa.ifa_netmask as *const libc::sockaddr_in6)).sin6_addr.s6_addr.iter().map(|b| b.count_ones()).sum()
+                    };
+                    InterfaceAddress { family: "ipv6".to_string(), address: Ipv6Addr::from(addr.sin6_addr.s6_addr).to_string(), prefix_len }
+                }
+                _ => continue,
+            };
+            map.entry(name).or_default().push(entry);
+        }
+        libc::freeifaddrs(ifap);
+    }
+    map
+}
+
+// Contadores por interface: /proc/net/dev no Linux
+#[cfg(target_os = "linux")]
+fn read_counters() -> std::io::Result<Vec<InterfaceCounters>> {
+    Ok(parse_net_dev(&std::fs::read_to_string("/proc/net/dev")?))
+}
+
+// No Windows os contadores vêm do GetIfTable2 (via sysinfo); descartes não são expostos
+#[cfg(not(target_os = "linux"))]
+fn read_counters() -> std::io::Result<Vec<InterfaceCounters>> {
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    Ok(networks.list().iter().map(|(name, data)| counters_from(name, data)).collect())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn counters_from(name: &str, data: &sysinfo::NetworkData) -> InterfaceCounters {
+    InterfaceCounters {
+        name: name.to_string(),
+        rx_bytes: data.total_received(),
+        rx_packets: data.total_packets_received(),
+        rx_errors: data.total_errors_on_received(),
+        rx_drops: 0,
+        tx_bytes: data.total_transmitted(),
+        tx_packets: data.total_packets_transmitted(),
+        tx_errors: data.total_errors_on_transmitted(),
+        tx_drops: 0,
+    }
+}
+
+// Interfaces com contadores e detalhes de /sys/class/net e getifaddrs
+#[cfg(target_os = "linux")]
+fn read_interfaces() -> std::io::Result<Vec<InterfaceInfo>> {
+    let mut addresses = interface_addresses();
+    let mut result = Vec::new();
+    for c in read_counters()? {
+        let sys = Path::new("/sys/class/net").join(&c.name);
+        result.push(InterfaceInfo {
+            mac: read_attr(&sys.join("address")),
+            mtu: read_attr(&sys.join("mtu")).and_then(|v| v.parse().ok()),
+            operstate: read_attr(&sys.join("operstate")),
+            carrier: read_flag(&sys.join("carrier")),
+            // Interfaces virtuais ou desconectadas reportam -1 ou erro
+            speed_mbps: read_attr(&sys.join("speed")).and_then(|v| v.parse::<i64>().ok()).filter(|v| *v > 0).map(|v| v as u64),
+            addresses: addresses.remove(&c.name).unwrap_or_default(),
+            rate: None,
+            name: c.name,
+            rx_bytes: c.rx_bytes,
+            rx_packets: c.rx_packets,
+            rx_errors: c.rx_errors,
+            rx_drops: c.rx_drops,
+            tx_bytes: c.tx_bytes,
+            tx_packets: c.tx_packets,
+            tx_errors: c.tx_errors,
+            tx_drops: c.tx_drops,
+        });
+    }
+    Ok(result)
+}
+
+// No Windows: GetIfTable2 (via sysinfo) dá contadores, MAC, MTU e endereços; estado e velocidade ficam ausentes
+#[cfg(not(target_os = "linux"))]
+fn read_interfaces() -> std::io::Result<Vec<InterfaceInfo>> {
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    let mut result: Vec<InterfaceInfo> = networks
+        .list()
+        .iter()
+        .map(|(name, data)| {
+            let c = counters_from(name, data);
+            InterfaceInfo {
+                mac: Some(data.mac_address()).filter(|m| !m.is_unspecified()).map(|m| m.to_string()),
+                mtu: u32::try_from(data.mtu()).ok().filter(|m| *m > 0),
+                operstate: None,
+                carrier: None,
+                speed_mbps: None,
+                addresses: data
+                    .ip_networks()
+                    .iter()
+                    .map(|n| InterfaceAddress {
+                        family: if n.addr.is_ipv4() { "ipv4" } else { "ipv6" }.to_string(),
+                        address: n.addr.to_string(),
+                        prefix_len: n.prefix as u32,
+                    })
+                    .collect(),
+                rate: None,
+                name: c.name,
+                rx_bytes: c.rx_bytes,
+                rx_packets: c.rx_packets,
+                rx_errors: c.rx_errors,
+                rx_drops: c.rx_drops,
+                tx_bytes: c.tx_bytes,
+                tx_packets: c.tx_packets,
+                tx_errors: c.tx_errors,
+                tx_drops: c.tx_drops,
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(result)
+}
+
+// Função para listar interfaces com contadores e, se disponível, as taxas do último tick do sampler
+pub async fn list_interfaces(rates: Vec<InterfaceRate>) -> Result<Vec<InterfaceInfo>, anyhow::Error> {
+    let mut interfaces = task::spawn_blocking(read_interfaces).await??;
+    let mut rates: HashMap<String, InterfaceRate> = rates.into_iter().map(|r| (r.name.clone(), r)).collect();
+    for interface in interfaces.iter_mut() {
+        interface.rate = rates.remove(&interface.name);
+    }
+    Ok(interfaces)
+}
+
+// Contadores acumulados de cada interface na leitura anterior (/proc/net/dev ou GetIfTable2), indexados pelo nome
+#[derive(Default)]
+pub struct NetworkCollector {
+    previous: Option<(Instant, HashMap<String, InterfaceCounters>)>,
+}
+
+impl NetworkCollector {
+    // Bytes, pacotes, erros e descartes por segundo de cada interface presente nas duas leituras, ordenados pelo
+    // nome; a primeira chamada devolve lista vazia e interfaces que surgiram entre os ticks só entram no próximo
+    pub fn sample(&mut self) -> std::io::Result<Vec<InterfaceRate>> {
+        let counters = read_counters()?;
+        let now = Instant::now();
+        let current: HashMap<String, InterfaceCounters> = counters.into_iter().map(|c| (c.name.clone(), c)).collect();
+        let mut rates = Vec::new();
+        if let Some((prev_at, prev)) = &self.previous {
+            let elapsed = now.duration_since(*prev_at).as_secs_f64();
+            for (name, cur) in &current {
+                if let Some(p) = prev.get(name) {
+                    rates.push(compute_rate(p, cur, elapsed));
+                }
+            }
+            rates.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        self.previous = Some((now, current));
+        Ok(rates)
+    }
+}
+
+pub mod connections;
+pub mod listeners;
@@ -1,7 +1,8 @@
 // Módulo para informações de handles abertos de processos
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, ToSchema, Debug, Clone)]
 pub struct HandleInfo {
     pub handle: u16,
     pub object_type: String,
@@ -9,8 +10,10 @@ pub struct HandleInfo {
     pub access: u32,
 }
 
+#[cfg(windows)]
 use std::ffi::c_void;
 
+#[cfg(windows)]
 #[repr(C)]
 #[allow(non_snake_case)]
 #[derive(Clone, Copy)]
@@ -23,6 +26,7 @@ pub struct SYSTEM_HANDLE {
     pub GrantedAccess: u32,
 }
 
+#[cfg(windows)]
 #[repr(C)]
 #[allow(non_snake_case)]
 pub struct SYSTEM_HANDLE_INFORMATION {
@@ -30,6 +34,7 @@ pub struct SYSTEM_HANDLE_INFORMATION {
     pub Handles: [SYSTEM_HANDLE; 1],
 }
 
+#[cfg(windows)]
 #[link(name = "ntdll")]
 extern "system" {
     pub fn NtQuerySystemInformation(
@@ -40,9 +45,11 @@ extern "system" {
     ) -> i32;
 }
 
+#[cfg(windows)]
 pub async fn list_process_handles(pid: u32) -> Result<Vec<HandleInfo>, anyhow::Error> {
     // Executa em thread separada para não travar o async
-    let handles = tokio::task::spawn_blocking(move || {
+    let span = tracing::Span::current();
+    let handles = tokio::task::spawn_blocking(move || span.in_scope(|| crate::logging::timed("handle_enumeration", || {
         const SYSTEM_HANDLE_INFORMATION_CLASS: u32 = 16;
         let mut size = 0x10000;
         let mut result = Vec::new();
@@ -83,6 +90,32 @@ pub async fn list_process_handles(pid: u32) -> Result<Vec<HandleInfo>, anyhow::E
             }
         }
         result
-    }).await?;
+    }))).await?;
+    Ok(handles)
+}
+
+// No Linux os handles são os descritores em /proc/<pid>/fd; o acesso vem das flags de abertura em fdinfo
+#[cfg(target_os = "linux")]
+pub async fn list_process_handles(pid: u32) -> Result<Vec<HandleInfo>, anyhow::Error> {
+    let span = tracing::Span::current();
+    let handles = tokio::task::spawn_blocking(move || span.in_scope(|| crate::logging::timed("handle_enumeration", || {
+        let fds = super::platform::open_fds(pid).ok_or_else(|| anyhow::anyhow!("sem acesso aos descritores do processo {}", pid))?;
+        Ok::<_, anyhow::Error>(
+            fds.into_iter()
+                .filter_map(|(fd, target)| {
+                    let fdinfo = std::fs::read_to_string(format!("/proc/{}/fdinfo/{}", pid, fd)).unwrap_or_default();
+                    let access = fdinfo.lines().find_map(|l| u32::from_str_radix(l.strip_prefix("flags:")?.trim(), 8).ok()).unwrap_or(0);
+                    let object_type = match super::platform::resource_kind(&target) {
+                        super::ResourceKind::File => "file",
+                        super::ResourceKind::Socket => "socket",
+                        super::ResourceKind::Pipe => "pipe",
+                        _ => target.split(':').next().unwrap_or("other"),
+                    }
+                    .to_string();
+                    Some(HandleInfo { handle: u16::try_from(fd).ok()?, object_type, name: target, access })
+                })
+                .collect(),
+        )
+    }))).await??;
     Ok(handles)
 }
@@ -0,0 +1,258 @@
+// Coleta de processos no Linux a partir do /proc (handles = descritores abertos, threads = /proc/<pid>/task)
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{OpenResource, ResourceKind, ThreadInfo};
+
+// Campos de /proc/<pid>/stat usados pela coleta
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct ProcStat {
+    pub state: char,
+    pub session: u32,
+    pub minor_faults: u64,
+    pub major_faults: u64,
+    pub utime_ticks: u64,
+    pub stime_ticks: u64,
+    pub priority: i32,
+    pub nice: i32,
+    pub num_threads: u32,
+}
+
+// O nome (campo 2) fica entre parênteses e pode conter espaços e ')'; os campos seguintes começam após o último ')'
+pub(super) fn parse_stat(content: &str) -> Option<ProcStat> {
+    let rest = &content[content.rfind(')')? + 1..];
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    // fields[0] é o campo 3 (estado); o campo n fica em fields[n - 3]
+    let field = |n: usize| fields.get(n - 3).copied();
+    Some(ProcStat {
+        state: field(3)?.chars().next()?,
+        session: field(6)?.parse().ok()?,
+        minor_faults: field(10)?.parse().ok()?,
+        major_faults: field(12)?.parse().ok()?,
+        utime_ticks: field(14)?.parse().ok()?,
+        stime_ticks: field(15)?.parse().ok()?,
+        priority: field(18)?.parse().ok()?,
+        nice: field(19)?.parse().ok()?,
+        num_threads: field(20)?.parse().ok()?,
+    })
+}
+
+// Valor de uma linha "Chave:\tvalor ..." de /proc/<pid>/status (primeiro campo numérico)
+pub(super) fn status_value(content: &str, key: &str) -> Option<u64> {
+    content
+        .lines()
+        .find_map(|l| l.strip_prefix(key)?.strip_prefix(':'))?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
+}
+
+// /proc/<pid>/io: bytes que chegaram ao armazenamento e número de chamadas de leitura/escrita
+pub(super) fn parse_io(content: &str) -> (Option<u64>, Option<u64>, Option<u64>, Option<u64>) {
+    let value = |key: &str| status_value(content, key);
+    (value("read_bytes"), value("write_bytes"), value("syscr"), value("syscw"))
+}
+
+// Tipo do recurso pelo destino do link em /proc/<pid>/fd
+pub(super) fn resource_kind(target: &str) -> ResourceKind {
+    if target.starts_with("socket:") {
+        ResourceKind::Socket
+    } else if target.starts_with("pipe:") {
+        ResourceKind::Pipe
+    } else if target.starts_with('/') {
+        ResourceKind::File
+    } else {
+        ResourceKind::Other
+    }
+}
+
+pub(super) fn thread_state(state: char) -> &'static str {
+    match state {
+        'R' => "running",
+        'S' => "sleeping",
+        'D' => "disk_sleep",
+        'Z' => "zombie",
+        'T' => "stopped",
+        't' => "tracing_stop",
+        'I' => "idle",
+        'X' | 'x' => "dead",
+        _ => "unknown",
+    }
+}
+
+fn proc_dir(pid: u32) -> std::path::PathBuf {
+    Path::new("/proc").join(pid.to_string())
+}
+
+fn read_stat(dir: &Path) -> Option<ProcStat> {
+    parse_stat(&std::fs::read_to_string(dir.join("stat")).ok()?)
+}
+
+fn clock_ticks_per_sec() -> u64 {
+    match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+        ticks if ticks > 0 => ticks as u64,
+        _ => 100,
+    }
+}
+
+// Descritores abertos (número, destino do link); None sem permissão para ler /proc/<pid>/fd
+pub(super) fn open_fds(pid: u32) -> Option<Vec<(u32, String)>> {
+    let dir = proc_dir(pid).join("fd");
+    let mut fds: Vec<(u32, String)> = std::fs::read_dir(&dir)
+        .ok()?
+        .flatten()
+        .filter_map(|e| {
+            let fd = e.file_name().to_str()?.parse().ok()?;
+            let target = std::fs::read_link(e.path()).ok()?;
+            Some((fd, target.to_string_lossy().to_string()))
+        })
+        .collect();
+    fds.sort();
+    Some(fds)
+}
+
+pub(super) fn get_process_handles_and_resources(pid: u32) -> (Option<u32>, Option<Vec<OpenResource>>) {
+    let Some(fds) = open_fds(pid) else { return (None, None) };
+    let resources = fds
+        .iter()
+        .filter_map(|(fd, target)| Some(OpenResource { kind: resource_kind(target), handle: u16::try_from(*fd).ok()? }))
+        .collect();
+    (Some(fds.len() as u32), Some(resources))
+}
+
+pub(super) fn get_process_handle_count(pid: u32) -> Option<u32> {
+    Some(std::fs::read_dir(proc_dir(pid).join("fd")).ok()?.count() as u32)
+}
+
+pub(super) fn get_process_threads(pid: u32) -> Vec<ThreadInfo> {
+    let tick_ms = 1000 / clock_ticks_per_sec().max(1);
+    let mut threads: Vec<ThreadInfo> = std::fs::read_dir(proc_dir(pid).join("task"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| {
+                    let tid = e.file_name().to_str()?.parse().ok()?;
+                    let dir = e.path();
+                    let stat = read_stat(&dir)?;
+                    let status = std::fs::read_to_string(dir.join("status")).unwrap_or_default();
+                    let switches = status_value(&status, "voluntary_ctxt_switches").zip(status_value(&status, "nonvoluntary_ctxt_switches"));
+                    // "0" quando a thread não está bloqueada em nenhuma função do kernel
+                    let wchan = std::fs::read_to_string(dir.join("wchan")).unwrap_or_default();
+                    Some(ThreadInfo {
+                        tid,
+                        base_priority: stat.priority,
+                        delta_priority: stat.nice,
+                        start_address: 0,
+                        state: thread_state(stat.state).to_string(),
+                        wait_reason: if wchan == "0" { String::new() } else { wchan.trim().to_string() },
+                        context_switches: switches.map(|(v, n)| v + n),
+                        user_time_ms: Some(stat.utime_ticks * tick_ms),
+                        kernel_time_ms: Some(stat.stime_ticks * tick_ms),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    threads.sort_by_key(|t| t.tid);
+    threads
+}
+
+// Usuário real (primeiro valor de Uid:) resolvido pelo banco de usuários do sistema
+pub(super) fn get_process_username(pid: u32) -> Option<String> {
+    let status = std::fs::read_to_string(proc_dir(pid).join("status")).ok()?;
+    let uid = status_value(&status, "Uid")? as libc::uid_t;
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut buf = vec![0 as libc::c_char; 4096];
+    let mut result: *mut libc::passwd = std::ptr::null_mut();
+    let rc = unsafe { libc::getpwuid_r(uid, &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+    if rc != 0 || result.is_null() {
+        // Sem entrada no passwd (ex.: contêineres) mostra o UID
+        return Some(uid.to_string());
+    }
+    Some(unsafe { std::ffi::CStr::from_ptr(pwd.pw_name) }.to_string_lossy().to_string())
+}
+
+pub(super) fn get_process_session_id(pid: u32) -> Option<u32> {
+    read_stat(&proc_dir(pid)).map(|s| s.session)
+}
+
+pub(super) fn get_process_priority(pid: u32) -> Option<i32> {
+    read_stat(&proc_dir(pid)).map(|s| s.priority)
+}
+
+// (faltas de página menores + maiores, pico do conjunto residente em kB)
+pub(super) fn get_process_memory_info(pid: u32) -> (u32, u64) {
+    let dir = proc_dir(pid);
+    let faults = read_stat(&dir).map(|s| (s.minor_faults + s.major_faults).min(u32::MAX as u64) as u32).unwrap_or(0);
+    let peak_kb = std::fs::read_to_string(dir.join("status")).ok().and_then(|s| status_value(&s, "VmHWM")).unwrap_or(0);
+    (faults, peak_kb)
+}
+
+pub(super) fn get_process_io(pid: u32) -> (Option<u64>, Option<u64>, Option<u64>, Option<u64>) {
+    match std::fs::read_to_string(proc_dir(pid).join("io")) {
+        Ok(content) => parse_io(&content),
+        Err(_) => (None, None, None, None),
+    }
+}
+
+// Threads de todos os processos, lidas do campo num_threads de cada /proc/<pid>/stat
+pub(super) fn get_thread_counts() -> HashMap<u32, u32> {
+    std::fs::read_dir("/proc")
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| {
+                    let pid = e.file_name().to_str()?.parse().ok()?;
+                    Some((pid, read_stat(&e.path())?.num_threads))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_stat_with_spaces_and_parens_in_name() {
+        let content = "4242 (my (odd) proc) S 1 4242 4242 0 -1 4194560 1500 0 12 0 350 120 0 0 20 0 3 0 12345 1000000 200 18446744073709551615 0 0 0 0 0 0 0 0 0 0 0 0 17 2 0 0 0 0 0\n";
+        let stat = parse_stat(content).unwrap();
+        assert_eq!(
+            stat,
+            ProcStat { state: 'S', session: 4242, minor_faults: 1500, major_faults: 12, utime_ticks: 350, stime_ticks: 120, priority: 20, nice: 0, num_threads: 3 }
+        );
+        assert_eq!(parse_stat("4242 (truncated) S 1"), None);
+    }
+
+    #[test]
+    fn reads_status_and_io_values() {
+        let status = "Name:\tbash\nUid:\t1000\t1000\t1000\t1000\nVmHWM:\t    5120 kB\nvoluntary_ctxt_switches:\t42\nnonvoluntary_ctxt_switches:\t7\n";
+        assert_eq!(status_value(status, "Uid"), Some(1000));
+        assert_eq!(status_value(status, "VmHWM"), Some(5120));
+        assert_eq!(status_value(status, "voluntary_ctxt_switches"), Some(42));
+        assert_eq!(status_value(status, "Missing"), None);
+        let io = "rchar: 100\nwchar: 200\nsyscr: 3\nsyscw: 4\nread_bytes: 4096\nwrite_bytes: 8192\ncancelled_write_bytes: 0\n";
+        assert_eq!(parse_io(io), (Some(4096), Some(8192), Some(3), Some(4)));
+    }
+
+    #[test]
+    fn classifies_fd_targets() {
+        assert_eq!(resource_kind("socket:[12345]"), ResourceKind::Socket);
+        assert_eq!(resource_kind("pipe:[678]"), ResourceKind::Pipe);
+        assert_eq!(resource_kind("/var/log/syslog"), ResourceKind::File);
+        assert_eq!(resource_kind("anon_inode:[eventfd]"), ResourceKind::Other);
+    }
+
+    #[test]
+    fn reads_own_process() {
+        let pid = std::process::id();
+        let (count, resources) = get_process_handles_and_resources(pid);
+        assert!(count.unwrap() >= 3);
+        assert!(!resources.unwrap().is_empty());
+        assert!(get_process_threads(pid).iter().any(|t| t.tid == pid));
+        assert!(get_process_username(pid).is_some());
+        assert!(get_thread_counts().get(&pid).is_some_and(|n| *n >= 1));
+    }
+}
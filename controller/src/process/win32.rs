@@ -0,0 +1,225 @@
+// APIs do Windows usadas na coleta de processos (handles, threads, usuário, memória e IO)
+use windows::Win32::Foundation::{HANDLE, CloseHandle};
+use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ, GetPriorityClass};
+use windows::Win32::System::Diagnostics::ToolHelp::{CreateToolhelp32Snapshot, Thread32First, Thread32Next, THREADENTRY32, TH32CS_SNAPTHREAD};
+use windows::Win32::System::ProcessStatus::{K32GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+use windows::Win32::System::Threading::{GetProcessHandleCount, GetProcessIoCounters, IO_COUNTERS};
+use windows::Win32::Security::{GetTokenInformation, TokenUser, LookupAccountSidW, TOKEN_QUERY, TOKEN_USER, SID_NAME_USE};
+// FFI manual para OpenProcessToken
+#[link(name = "advapi32")]
+extern "system" {
+    fn OpenProcessToken(
+        ProcessHandle: HANDLE,
+        DesiredAccess: u32,
+        TokenHandle: *mut HANDLE,
+    ) -> i32;
+}
+use windows::core::PWSTR;
+
+use std::ptr;
+use std::ffi::OsString;
+use std::os::windows::ffi::OsStringExt;
+
+// --- Handles e recursos abertos por processo ---
+use super::handle::{NtQuerySystemInformation, SYSTEM_HANDLE, SYSTEM_HANDLE_INFORMATION};
+use super::{OpenResource, ResourceKind, ThreadInfo};
+use std::collections::HashMap;
+
+pub(super) fn get_process_handles_and_resources(pid: u32) -> (Option<u32>, Option<Vec<OpenResource>>) {
+    unsafe {
+        let mut buffer = vec![0u8; 1024 * 1024];
+        let mut return_length = 0u32;
+        let status = NtQuerySystemInformation(
+            16, // SystemHandleInformation
+            buffer.as_mut_ptr() as *mut _,
+            buffer.len() as u32,
+            &mut return_length,
+        );
+        if status != 0 {
+            return (None, None);
+        }
+        let handle_info = buffer.as_ptr() as *const SYSTEM_HANDLE_INFORMATION;
+        let handle_count = (*handle_info).HandleCount;
+        let handle_ptr = &(*handle_info).Handles as *const SYSTEM_HANDLE;
+        let mut resources = Vec::new();
+        let mut count = 0u32;
+        for i in 0..handle_count {
+            let handle = *handle_ptr.add(i as usize);
+            if handle.ProcessId != pid { continue; }
+            count += 1;
+            // Tenta identificar o tipo do handle (simplificado)
+            let kind = match handle.ObjectTypeNumber {
+                0x1C | 0x1F => ResourceKind::File,
+                0x1E => ResourceKind::Mutex,
+                0x1D => ResourceKind::Semaphore,
+                0x1B => ResourceKind::Pipe,
+                0x1A => ResourceKind::Socket,
+                _ => ResourceKind::Other,
+            };
+            resources.push(OpenResource { kind, handle: handle.Handle });
+        }
+        (Some(count), Some(resources))
+    }
+}
+// Helper para obter detalhes de threads do processo
+pub(super) fn get_process_threads(pid: u32) -> Vec<ThreadInfo> {
+    let mut threads = Vec::new();
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0).unwrap();
+        let mut entry = THREADENTRY32 { dwSize: std::mem::size_of::<THREADENTRY32>() as u32, ..Default::default() };
+        if Thread32First(snapshot, &mut entry).is_ok() {
+            loop {
+                if entry.th32OwnerProcessID == pid {
+                    threads.push(ThreadInfo {
+                        tid: entry.th32ThreadID,
+                        base_priority: entry.tpBasePri as i32,
+                        delta_priority: entry.tpDeltaPri as i32,
+                        start_address: 0,
+                        state: String::new(),
+                        wait_reason: String::new(),
+                        context_switches: None,
+                        user_time_ms: None,
+                        kernel_time_ms: None,
+                    });
+                }
+                if Thread32Next(snapshot, &mut entry).is_err() { break; }
+            }
+        }
+        CloseHandle(snapshot).ok();
+    }
+    threads
+}
+// Helper para abrir processo com fallback
+fn open_process(pid: u32) -> Option<HANDLE> {
+    unsafe {
+        OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, false, pid).ok()
+    }
+}
+
+// Helper para obter usuário do processo
+pub(super) fn get_process_username(pid: u32) -> Option<String> {
+    unsafe {
+        let handle = open_process(pid)?;
+        let mut token = HANDLE(ptr::null_mut());
+        if OpenProcessToken(handle, TOKEN_QUERY.0, &mut token) == 0 {
+            CloseHandle(handle).ok();
+            return None;
+        }
+        let mut ret_len = 0u32;
+        let _ = GetTokenInformation(token, TokenUser, None, 0, &mut ret_len);
+        if ret_len == 0 {
+            CloseHandle(token).ok();
+            CloseHandle(handle).ok();
+            return None;
+        }
+        let mut buf = vec![0u8; ret_len as usize];
+        let ok = GetTokenInformation(token, TokenUser, Some(buf.as_mut_ptr() as _), ret_len, &mut ret_len).is_ok();
+        if !ok {
+            CloseHandle(token).ok();
+            CloseHandle(handle).ok();
+            return None;
+        }
+        let user = &*(buf.as_ptr() as *const TOKEN_USER);
+        let mut name = [0u16; 256];
+        let mut name_len = name.len() as u32;
+        let mut domain = [0u16; 256];
+        let mut domain_len = domain.len() as u32;
+        let mut sid_type = SID_NAME_USE(0);
+        let ok = LookupAccountSidW(
+            None,
+            user.User.Sid,
+            Some(PWSTR(name.as_mut_ptr())),
+            &mut name_len,
+            Some(PWSTR(domain.as_mut_ptr())),
+            &mut domain_len,
+            &mut sid_type
+        ).is_ok();
+        let result = if ok {
+            let name = OsString::from_wide(&name[..name_len as usize]).to_string_lossy().to_string();
+            let domain = OsString::from_wide(&domain[..domain_len as usize]).to_string_lossy().to_string();
+            Some(format!("{}\\{}", domain, name))
+        } else {
+            None
+        };
+        CloseHandle(token).ok();
+        CloseHandle(handle).ok();
+        result
+    }
+}
+
+#[allow(non_snake_case)]
+pub(super) fn get_process_session_id(_pid: u32) -> Option<u32> {
+    None
+}
+
+// Helper para obter prioridade
+pub(super) fn get_process_priority(pid: u32) -> Option<i32> {
+    unsafe {
+        let handle = open_process(pid)?;
+        let prio = GetPriorityClass(handle);
+        CloseHandle(handle).ok();
+        if prio != 0 { Some(prio as i32) } else { None }
+    }
+}
+
+// Helper para obter info de memória
+pub(super) fn get_process_memory_info(pid: u32) -> (u32, u64) {
+    unsafe {
+        if let Some(handle) = open_process(pid) {
+            let mut mem_counters = PROCESS_MEMORY_COUNTERS::default();
+            if K32GetProcessMemoryInfo(handle, &mut mem_counters, std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32).as_bool() {
+                CloseHandle(handle).ok();
+                return (mem_counters.PageFaultCount, mem_counters.PeakWorkingSetSize as u64 / 1024);
+            } else {
+                CloseHandle(handle).ok();
+                return (0, 0);
+            }
+        }
+    }
+    (0, 0)
+}
+
+// Helper para obter IO
+pub(super) fn get_process_io(pid: u32) -> (Option<u64>, Option<u64>, Option<u64>, Option<u64>) {
+    unsafe {
+        if let Some(handle) = open_process(pid) {
+            let mut io = IO_COUNTERS::default();
+            if GetProcessIoCounters(handle, &mut io).is_ok() {
+                CloseHandle(handle).ok();
+                return (Some(io.ReadTransferCount), Some(io.WriteTransferCount), Some(io.ReadOperationCount), Some(io.WriteOperationCount));
+            } else {
+                CloseHandle(handle).ok();
+                return (None, None, None, None);
+            }
+        }
+    }
+    (None, None, None, None)
+}
+
+// Contagem de handles do próprio processo, sem a enumeração de todos os handles do sistema
+pub(super) fn get_process_handle_count(pid: u32) -> Option<u32> {
+    unsafe {
+        let handle = open_process(pid)?;
+        let mut count = 0u32;
+        let ok = GetProcessHandleCount(handle, &mut count).is_ok();
+        CloseHandle(handle).ok();
+        ok.then_some(count)
+    }
+}
+
+// Threads de todos os processos a partir de um único snapshot
+pub(super) fn get_thread_counts() -> HashMap<u32, u32> {
+    let mut counts = HashMap::new();
+    unsafe {
+        let Ok(snapshot) = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0) else { return counts };
+        let mut entry = THREADENTRY32 { dwSize: std::mem::size_of::<THREADENTRY32>() as u32, ..Default::default() };
+        if Thread32First(snapshot, &mut entry).is_ok() {
+            loop {
+                *counts.entry(entry.th32OwnerProcessID).or_insert(0) += 1;
+                if Thread32Next(snapshot, &mut entry).is_err() { break; }
+            }
+        }
+        CloseHandle(snapshot).ok();
+    }
+    counts
+}
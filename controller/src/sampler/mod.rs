@@ -0,0 +1,298 @@
+// Módulo do sampler: coleta métricas periodicamente em segundo plano e guarda o histórico
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio::task;
+
+use crate::config::SubsystemsConfig;
+use crate::health::{OwnCollector, OwnUsage};
+use crate::fs::diskstats::{DiskIoCollector, DiskIoRate};
+use crate::network::listeners::{ListenerChange, ListenerCollector};
+use crate::network::{InterfaceRate, NetworkCollector};
+use crate::system::cpu::{CpuBreakdown, CpuTimesCollector};
+use crate::system::interrupts::{InterruptsCollector, InterruptsSnapshot};
+use crate::system::load::LoadInfo;
+use crate::system::memory::{SwapCollector, SwapRate};
+use crate::system::sensors::SensorReading;
+
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
+pub const DEFAULT_RETENTION: usize = 300;
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct Sample<T> {
+    pub timestamp: i64,
+    pub value: T,
+}
+
+// Buffer circular de amostras com capacidade fixa
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    capacity: usize,
+    samples: VecDeque<Sample<T>>,
+}
+
+impl<T: Clone> History<T> {
+    pub fn new(capacity: usize) -> Self {
+        History { capacity, samples: VecDeque::with_capacity(capacity) }
+    }
+
+    pub fn push(&mut self, timestamp: i64, value: T) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(Sample { timestamp, value });
+    }
+
+    pub fn latest(&self) -> Option<&Sample<T>> {
+        self.samples.back()
+    }
+
+    pub fn to_vec(&self) -> Vec<Sample<T>> {
+        self.samples.iter().cloned().collect()
+    }
+}
+
+// Duração de um tick, total e por coletor (em ms)
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct TickTiming {
+    pub total_ms: f64,
+    pub collectors: BTreeMap<String, f64>,
+}
+
+// Estado compartilhado lido pelos handlers HTTP
+pub struct SamplerState {
+    pub interval_ms: u64,
+    pub last_tick: Option<i64>,
+    pub ticks: u64,
+    pub tick_timings: History<TickTiming>,
+    pub own: Option<OwnUsage>,
+    pub disk_io: History<Vec<DiskIoRate>>,
+    pub network: History<Vec<InterfaceRate>>,
+    pub listener_changes: History<ListenerChange>,
+    pub cpu_times: History<CpuBreakdown>,
+    pub load: History<LoadInfo>,
+    pub swap: History<SwapRate>,
+    pub sensors: History<Vec<SensorReading>>,
+    // Matriz IRQ x CPU é grande demais para manter histórico; guarda só a última leitura
+    pub interrupts: Option<InterruptsSnapshot>,
+}
+
+impl SamplerState {
+    fn new(interval: Duration, retention: usize) -> Self {
+        SamplerState {
+            interval_ms: interval.as_millis() as u64,
+            last_tick: None,
+            ticks: 0,
+            tick_timings: History::new(retention),
+            own: None,
+            disk_io: History::new(retention),
+            network: History::new(retention),
+            listener_changes: History::new(retention),
+            cpu_times: History::new(retention),
+            load: History::new(retention),
+            swap: History::new(retention),
+            sensors: History::new(retention),
+            interrupts: None,
+        }
+    }
+}
+
+pub static STATE: Lazy<RwLock<SamplerState>> = Lazy::new(|| RwLock::new(SamplerState::new(DEFAULT_INTERVAL, DEFAULT_RETENTION)));
+
+// Coletores que precisam da leitura anterior para calcular taxas
+#[derive(Default)]
+struct Collectors {
+    enabled: SubsystemsConfig,
+    disk_io: DiskIoCollector,
+    network: NetworkCollector,
+    listeners: ListenerCollector,
+    swap: SwapCollector,
+    cpu_times: CpuTimesCollector,
+    interrupts: InterruptsCollector,
+    own: OwnCollector,
+}
+
+struct TickResult {
+    disk_io: Option<Vec<DiskIoRate>>,
+    network: Option<Vec<InterfaceRate>>,
+    listener_changes: Vec<ListenerChange>,
+    cpu_times: Option<CpuBreakdown>,
+    load: Option<LoadInfo>,
+    swap: Option<SwapRate>,
+    sensors: Vec<SensorReading>,
+    interrupts: Option<InterruptsSnapshot>,
+    own: Option<OwnUsage>,
+    timing: TickTiming,
+}
+
+// Executa um coletor habilitado e guarda quanto tempo ele levou na duração do tick
+fn collect<T: Default>(timings: &mut BTreeMap<String, f64>, name: &str, enabled: bool, run: impl FnOnce() -> T) -> T {
+    if !enabled {
+        return T::default();
+    }
+    let (value, elapsed_ms) = crate::logging::measure(run);
+    timings.insert(name.to_string(), elapsed_ms);
+    value
+}
+
+impl Collectors {
+    fn tick(&mut self) -> TickResult {
+        let on = self.enabled;
+        let started = Instant::now();
+        let mut t = BTreeMap::new();
+        TickResult {
+            // Sem /proc/diskstats nem IOCTL_DISK_PERFORMANCE não há amostra de disco
+            disk_io: collect(&mut t, "disk_io", on.storage, || self.disk_io.sample().ok()),
+            network: collect(&mut t, "network", on.network, || self.network.sample().ok()),
+            listener_changes: collect(&mut t, "listeners", on.network, || self.listeners.sample()),
+            cpu_times: collect(&mut t, "cpu_times", on.cpu, || self.cpu_times.sample().ok().flatten()),
+            load: collect(&mut t, "load", on.system, || crate::system::load::read_load().ok()),
+            swap: collect(&mut t, "swap", on.system, || self.swap.sample().ok().flatten()),
+            sensors: collect(&mut t, "sensors", on.sensors, crate::system::sensors::read_sensors),
+            interrupts: collect(&mut t, "interrupts", on.system, || self.interrupts.sample().ok()),
+            // O uso do próprio controller é coletado mesmo com todos os subsistemas desligados
+            own: collect(&mut t, "own", true, || self.own.sample()),
+            timing: TickTiming { total_ms: started.elapsed().as_secs_f64() * 1000.0, collectors: t },
+        }
+    }
+}
+
+// Funções de leitura usadas pelos handlers
+pub fn latest_disk_io() -> Vec<DiskIoRate> {
+    STATE.read().unwrap().disk_io.latest().map(|s| s.value.clone()).unwrap_or_default()
+}
+
+pub fn disk_io_history() -> Vec<Sample<Vec<DiskIoRate>>> {
+    STATE.read().unwrap().disk_io.to_vec()
+}
+
+pub fn latest_network() -> Vec<InterfaceRate> {
+    STATE.read().unwrap().network.latest().map(|s| s.value.clone()).unwrap_or_default()
+}
+
+pub fn network_history() -> Vec<Sample<Vec<InterfaceRate>>> {
+    STATE.read().unwrap().network.to_vec()
+}
+
+pub fn listener_changes() -> Vec<Sample<ListenerChange>> {
+    STATE.read().unwrap().listener_changes.to_vec()
+}
+
+pub fn latest_cpu_times() -> Option<CpuBreakdown> {
+    STATE.read().unwrap().cpu_times.latest().map(|s| s.value.clone())
+}
+
+pub fn cpu_times_history() -> Vec<Sample<CpuBreakdown>> {
+    STATE.read().unwrap().cpu_times.to_vec()
+}
+
+pub fn load_history() -> Vec<Sample<LoadInfo>> {
+    STATE.read().unwrap().load.to_vec()
+}
+
+pub fn latest_swap_rate() -> Option<SwapRate> {
+    STATE.read().unwrap().swap.latest().map(|s| s.value.clone())
+}
+
+pub fn swap_history() -> Vec<Sample<SwapRate>> {
+    STATE.read().unwrap().swap.to_vec()
+}
+
+pub fn latest_interrupts() -> Option<InterruptsSnapshot> {
+    STATE.read().unwrap().interrupts.clone()
+}
+
+// (último tick, intervalo configurado em ms), usados pelo /readyz
+pub fn tick_status() -> (Option<i64>, u64) {
+    let state = STATE.read().unwrap();
+    (state.last_tick, state.interval_ms)
+}
+
+pub fn tick_count() -> u64 {
+    STATE.read().unwrap().ticks
+}
+
+pub fn tick_timings() -> Vec<Sample<TickTiming>> {
+    STATE.read().unwrap().tick_timings.to_vec()
+}
+
+pub fn own_usage() -> Option<OwnUsage> {
+    STATE.read().unwrap().own.clone()
+}
+
+pub fn sensors_history() -> Vec<Sample<Vec<SensorReading>>> {
+    STATE.read().unwrap().sensors.to_vec()
+}
+
+// Função para iniciar o sampler em segundo plano; subsistemas desligados não são coletados
+pub fn spawn(interval: Duration, retention: usize, enabled: SubsystemsConfig) {
+    *STATE.write().unwrap() = SamplerState::new(interval, retention);
+    tokio::spawn(async move {
+        let mut collectors = Collectors { enabled, ..Default::default() };
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            ticker.tick().await;
+            let (returned, result) = match task::spawn_blocking(move || {
+                let result = collectors.tick();
+                (collectors, result)
+            })
+            .await
+            {
+                Ok(r) => r,
+                Err(e) => {
+                    // Os coletores se perderam no pânico; recomeça do zero e as taxas voltam no tick seguinte
+                    tracing::error!(error = %e, "coleta do sampler falhou; tick descartado");
+                    collectors = Collectors { enabled, ..Default::default() };
+                    continue;
+                }
+            };
+            collectors = returned;
+            // Um tick mais longo que o intervalo atrasa os seguintes e deixa o /readyz em risco
+            if result.timing.total_ms > interval.as_secs_f64() * 1000.0 {
+                tracing::warn!(total_ms = result.timing.total_ms, collectors = ?result.timing.collectors, "tick do sampler excedeu o intervalo");
+            } else {
+                tracing::trace!(total_ms = result.timing.total_ms, collectors = ?result.timing.collectors, "tick do sampler");
+            }
+            let now = chrono::Utc::now().timestamp_millis();
+            let mut state = STATE.write().unwrap();
+            state.last_tick = Some(now);
+            state.ticks += 1;
+            state.tick_timings.push(now, result.timing);
+            if result.own.is_some() {
+                state.own = result.own;
+            }
+            // A primeira leitura só serve de base para as taxas
+            if let Some(disk_io) = result.disk_io.filter(|d| !d.is_empty()) {
+                state.disk_io.push(now, disk_io);
+            }
+            if let Some(network) = result.network.filter(|n| !n.is_empty()) {
+                state.network.push(now, network);
+            }
+            if let Some(cpu_times) = result.cpu_times {
+                state.cpu_times.push(now, cpu_times);
+            }
+            if let Some(load) = result.load {
+                state.load.push(now, load);
+            }
+            if let Some(swap) = result.swap {
+                state.swap.push(now, swap);
+            }
+            if !result.sensors.is_empty() {
+                state.sensors.push(now, result.sensors);
+            }
+            if result.interrupts.is_some() {
+                state.interrupts = result.interrupts;
+            }
+            for change in result.listener_changes {
+                state.listener_changes.push(now, change);
+            }
+        }
+    });
+}
@@ -0,0 +1,48 @@
+// Leitura de atributos de /sys e /proc: um valor por arquivo, com quebra de linha no fim
+use std::path::Path;
+
+// Conteúdo sem espaços nas pontas; arquivo ausente, ilegível ou vazio vira None
+pub fn read_attr(path: &Path) -> Option<String> {
+    let value = std::fs::read_to_string(path).ok()?;
+    let value = value.trim();
+    if value.is_empty() { None } else { Some(value.to_string()) }
+}
+
+// Atributos booleanos do kernel ("0"/"1"), como removable, ro e carrier
+pub fn read_flag(path: &Path) -> Option<bool> {
+    read_attr(path).map(|v| v == "1")
+}
+
+pub fn read_number(path: &Path) -> Option<f64> {
+    read_attr(path)?.parse().ok()
+}
+
+// Nomes das entradas de um diretório em ordem (ex.: holders/, slaves/); diretório ausente dá lista vazia
+pub fn list_names(path: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|e| e.file_name().to_string_lossy().to_string()).collect())
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn reads_trimmed_attributes() {
+        let root = TempDir::with_files(
+            "sysfs",
+            &[("vendor", "ATA     \n"), ("empty", "\n"), ("ro", "1\n"), ("carrier", "0\n"), ("temp", "42500\n"), ("dir/b", ""), ("dir/a", "")],
+        );
+        let attr = |name: &str| root.path().join(name);
+        assert_eq!(read_attr(&attr("vendor")).as_deref(), Some("ATA"));
+        assert_eq!((read_attr(&attr("empty")), read_attr(&attr("missing"))), (None, None));
+        assert_eq!((read_flag(&attr("ro")), read_flag(&attr("carrier")), read_flag(&attr("missing"))), (Some(true), Some(false), None));
+        assert_eq!((read_number(&attr("temp")), read_number(&attr("vendor"))), (Some(42500.0), None));
+        assert_eq!(list_names(&attr("dir")), vec!["a", "b"]);
+        assert!(list_names(&attr("missing")).is_empty());
+    }
+}
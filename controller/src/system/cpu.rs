@@ -0,0 +1,230 @@
+// Módulo para detalhamento de tempo de CPU por núcleo (/proc/stat) e frequência (cpufreq)
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Default)]
+pub struct CpuCounters {
+    pub name: String,
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+}
+
+impl CpuCounters {
+    // guest/guest_nice já estão incluídos em user/nice, por isso ficam fora do total
+    fn total(&self) -> u64 {
+        self.user + self.nice + self.system + self.idle + self.iowait + self.irq + self.softirq + self.steal
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Default)]
+pub struct CpuTimes {
+    pub cpu: String,
+    pub user: f64,
+    pub nice: f64,
+    pub system: f64,
+    pub idle: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub softirq: f64,
+    pub steal: f64,
+    pub busy: f64,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Default)]
+pub struct CpuBreakdown {
+    pub total: CpuTimes,
+    pub per_core: Vec<CpuTimes>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct CoreFrequency {
+    pub cpu: u32,
+    pub current_mhz: Option<u64>,
+    pub min_mhz: Option<u64>,
+    pub max_mhz: Option<u64>,
+    pub governor: Option<String>,
+}
+
+// Função para extrair as linhas "cpu" e "cpuN" de /proc/stat
+pub fn parse_cpu_counters(content: &str) -> Vec<CpuCounters> {
+    content
+        .lines()
+        .filter(|l| l.starts_with("cpu"))
+        .filter_map(|line| {
+            let mut f = line.split_whitespace();
+            let name = f.next()?.to_string();
+            let v: Vec<u64> = f.filter_map(|x| x.parse().ok()).collect();
+            let get = |i: usize| v.get(i).copied().unwrap_or(0);
+            Some(CpuCounters {
+                name,
+                user: get(0),
+                nice: get(1),
+                system: get(2),
+                idle: get(3),
+                iowait: get(4),
+                irq: get(5),
+                softirq: get(6),
+                steal: get(7),
+            })
+        })
+        .collect()
+}
+
+pub fn compute_times(prev: &CpuCounters, cur: &CpuCounters) -> CpuTimes {
+    let total = cur.total().saturating_sub(prev.total()) as f64;
+    let pct = |a: u64, b: u64| if total > 0.0 { b.saturating_sub(a) as f64 / total * 100.0 } else { 0.0 };
+    let idle = pct(prev.idle, cur.idle);
+    let iowait = pct(prev.iowait, cur.iowait);
+    CpuTimes {
+        cpu: cur.name.clone(),
+        user: pct(prev.user, cur.user),
+        nice: pct(prev.nice, cur.nice),
+        system: pct(prev.system, cur.system),
+        idle,
+        iowait,
+        irq: pct(prev.irq, cur.irq),
+        softirq: pct(prev.softirq, cur.softirq),
+        steal: pct(prev.steal, cur.steal),
+        // Soma dos campos ocupados em vez de 100 - idle: com um contador zerado (idle menor que antes) o núcleo
+        // apareceria 100% ocupado
+        busy: (pct(prev.user, cur.user)
+            + pct(prev.nice, cur.nice)
+            + pct(prev.system, cur.system)
+            + pct(prev.irq, cur.irq)
+            + pct(prev.softirq, cur.softirq)
+            + pct(prev.steal, cur.steal))
+        .min(100.0),
+    }
+}
+
+fn read_khz_as_mhz(path: &Path) -> Option<u64> {
+    std::fs::read_to_string(path).ok()?.trim().parse::<u64>().ok().map(|khz| khz / 1000)
+}
+
+// Função para ler a frequência de cada núcleo a partir de uma raiz sysfs (normalmente "/sys")
+pub fn read_frequencies_at(sys_root: &Path) -> Vec<CoreFrequency> {
+    let cpu_dir = sys_root.join("devices/system/cpu");
+    let mut cores: Vec<u32> = std::fs::read_dir(&cpu_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| e.file_name().to_str().and_then(|n| n.strip_prefix("cpu")).and_then(|n| n.parse().ok()))
+                .collect()
+        })
+        .unwrap_or_default();
+    cores.sort();
+    cores
+        .into_iter()
+        .map(|cpu| {
+            let freq = cpu_dir.join(format!("cpu{}/cpufreq", cpu));
+            CoreFrequency {
+                cpu,
+                current_mhz: read_khz_as_mhz(&freq.join("scaling_cur_freq")),
+                min_mhz: read_khz_as_mhz(&freq.join("cpuinfo_min_freq")),
+                max_mhz: read_khz_as_mhz(&freq.join("cpuinfo_max_freq")),
+                governor: std::fs::read_to_string(freq.join("scaling_governor")).ok().map(|g| g.trim().to_string()),
+            }
+        })
+        .collect()
+}
+
+pub fn read_frequencies() -> Vec<CoreFrequency> {
+    read_frequencies_at(Path::new("/sys"))
+}
+
+// Contadores em jiffies da leitura anterior de /proc/stat (linha agregada "cpu" e uma por núcleo).
+// Só existe no Linux: no Windows o detalhamento (times em /api/v2/system/cpu) fica null e o histórico vazio
+pub struct CpuTimesCollector {
+    proc_root: PathBuf,
+    previous: Option<Vec<CpuCounters>>,
+}
+
+impl Default for CpuTimesCollector {
+    fn default() -> Self {
+        CpuTimesCollector::new("/proc")
+    }
+}
+
+impl CpuTimesCollector {
+    pub fn new(proc_root: impl Into<PathBuf>) -> Self {
+        CpuTimesCollector { proc_root: proc_root.into(), previous: None }
+    }
+
+    // Percentuais entre esta leitura e a anterior, casando núcleos pelo nome; a primeira só guarda os contadores
+    // (None). Núcleo sem leitura anterior (CPU que voltou a ficar online) fica de fora até o próximo tick
+    pub fn sample(&mut self) -> std::io::Result<Option<CpuBreakdown>> {
+        if !cfg!(target_os = "linux") {
+            return Err(std::io::ErrorKind::Unsupported.into());
+        }
+        let current = parse_cpu_counters(&std::fs::read_to_string(self.proc_root.join("stat"))?);
+        let breakdown = self.previous.as_ref().map(|prev| {
+            let mut times = current.iter().filter_map(|cur| prev.iter().find(|p| p.name == cur.name).map(|p| compute_times(p, cur)));
+            let total = times.next().unwrap_or_default();
+            CpuBreakdown { total, per_core: times.collect() }
+        });
+        self.previous = Some(current);
+        Ok(breakdown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counters(name: &str, user: u64, system: u64, idle: u64, iowait: u64) -> CpuCounters {
+        CpuCounters { name: name.to_string(), user, system, idle, iowait, ..Default::default() }
+    }
+
+    #[test]
+    fn parses_aggregate_and_core_lines() {
+        let stat = "cpu  400 10 200 3000 50 5 5 0 0 0\ncpu0 200 5 100 1500 25 3 2 0 0 0\ncpu1 200 5 100 1500 25 2 3\nintr 12345\nctxt 999\n";
+        let cpus = parse_cpu_counters(stat);
+        let names: Vec<&str> = cpus.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["cpu", "cpu0", "cpu1"]);
+        assert_eq!(cpus[0].total(), 3670);
+        // Kernels antigos não têm a coluna steal
+        assert_eq!(cpus[2].steal, 0);
+    }
+
+    #[test]
+    fn computes_percentages_between_samples() {
+        let times = compute_times(&counters("cpu0", 100, 100, 700, 100), &counters("cpu0", 150, 100, 730, 120));
+        assert_eq!((times.user, times.system, times.idle, times.iowait), (50.0, 0.0, 30.0, 20.0));
+        assert_eq!(times.busy, 50.0);
+    }
+
+    #[test]
+    fn zero_total_and_wrapped_counters_do_not_blow_up() {
+        // Nenhum jiffy entre as leituras (ticks muito próximos)
+        let same = counters("cpu0", 100, 50, 800, 10);
+        let times = compute_times(&same, &same);
+        assert_eq!((times.user, times.idle, times.busy), (0.0, 0.0, 0.0));
+        // Contador que voltou para trás (estouro ou núcleo reiniciado) conta como zero, sem virar 100% ocupado
+        let times = compute_times(&counters("cpu0", 100, 50, 800, 10), &counters("cpu0", 600, 60, 500, 10));
+        assert!(times.user.is_finite() && times.idle == 0.0 && times.busy <= 100.0);
+        // Total menor que o anterior: tudo zero
+        let times = compute_times(&counters("cpu0", 100, 50, 800, 10), &counters("cpu0", 1, 1, 1, 1));
+        assert_eq!((times.user, times.busy), (0.0, 0.0));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn sample_needs_two_readings_and_matches_cores_by_name() {
+        let proc_root = crate::testutil::TempDir::with_files("cpu-times", &[("stat", "cpu  100 0 100 800 0 0 0 0\ncpu0 100 0 100 800 0 0 0 0\n")]);
+        let mut collector = CpuTimesCollector::new(proc_root.path());
+        assert!(collector.sample().unwrap().is_none());
+        proc_root.write("stat", "cpu  200 0 100 900 0 0 0 0\ncpu0 200 0 100 900 0 0 0 0\ncpu1 5 0 5 90 0 0 0 0\n");
+        let breakdown = collector.sample().unwrap().unwrap();
+        assert_eq!((breakdown.total.cpu.as_str(), breakdown.total.user, breakdown.total.busy), ("cpu", 50.0, 50.0));
+        // cpu1 apareceu agora e ainda não tem base de comparação
+        let cores: Vec<&str> = breakdown.per_core.iter().map(|c| c.cpu.as_str()).collect();
+        assert_eq!(cores, vec!["cpu0"]);
+    }
+}
@@ -0,0 +1,256 @@
+// Módulo para distribuição de interrupções e softirqs por CPU (/proc/interrupts, /proc/softirqs)
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use crate::sysfs::read_attr;
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct IrqStats {
+    pub irq: String,
+    pub description: String,
+    pub counts: Vec<u64>,
+    pub total: u64,
+    pub deltas: Option<Vec<u64>>,
+    pub rate_per_sec: Option<f64>,
+    pub affinity_mask: Option<String>,
+    pub affinity_list: Option<String>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct SoftirqStats {
+    pub name: String,
+    pub counts: Vec<u64>,
+    pub total: u64,
+    pub deltas: Option<Vec<u64>>,
+    pub rate_per_sec: Option<f64>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Default)]
+pub struct InterruptsSnapshot {
+    pub cpus: Vec<String>,
+    pub interval_secs: Option<f64>,
+    pub irqs: Vec<IrqStats>,
+    pub softirqs: Vec<SoftirqStats>,
+}
+
+// (nome, contadores por CPU, descrição)
+type TableRow = (String, Vec<u64>, String);
+
+// Interpreta o formato comum aos dois arquivos: cabeçalho com CPUs e linhas "NOME: c0 c1 ... descrição"
+fn parse_table(content: &str) -> (Vec<String>, Vec<TableRow>) {
+    let mut lines = content.lines();
+    let cpus: Vec<String> = lines.next().map(|h| h.split_whitespace().map(String::from).collect()).unwrap_or_default();
+    let rows = lines
+        .filter_map(|line| {
+            let (name, rest) = line.split_once(':')?;
+            let mut tokens = rest.split_whitespace().peekable();
+            let mut counts = Vec::with_capacity(cpus.len());
+            // Linhas como "ERR:" e "MIS:" têm um único contador global
+            while counts.len() < cpus.len() {
+                match tokens.peek().and_then(|t| t.parse::<u64>().ok()) {
+                    Some(v) => {
+                        counts.push(v);
+                        tokens.next();
+                    }
+                    None => break,
+                }
+            }
+            Some((name.trim().to_string(), counts, tokens.collect::<Vec<_>>().join(" ")))
+        })
+        .collect();
+    (cpus, rows)
+}
+
+// Função para ler interrupções e softirqs a partir de uma raiz procfs (normalmente "/proc")
+pub fn read_interrupts_at(proc_root: &Path) -> std::io::Result<InterruptsSnapshot> {
+    let (cpus, irq_rows) = parse_table(&std::fs::read_to_string(proc_root.join("interrupts"))?);
+    let irqs = irq_rows
+        .into_iter()
+        .map(|(irq, counts, description)| {
+            // Apenas IRQs numéricas têm afinidade configurável
+            let irq_dir = proc_root.join("irq").join(&irq);
+            let numeric = irq.chars().all(|c| c.is_ascii_digit());
+            IrqStats {
+                total: counts.iter().sum(),
+                affinity_mask: if numeric { read_attr(&irq_dir.join("smp_affinity")) } else { None },
+                affinity_list: if numeric { read_attr(&irq_dir.join("smp_affinity_list")) } else { None },
+                irq,
+                description,
+                counts,
+                deltas: None,
+                rate_per_sec: None,
+            }
+        })
+        .collect();
+    let softirqs = match std::fs::read_to_string(proc_root.join("softirqs")) {
+        Ok(content) => parse_table(&content)
+            .1
+            .into_iter()
+            .map(|(name, counts, _)| SoftirqStats { total: counts.iter().sum(), name, counts, deltas: None, rate_per_sec: None })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    Ok(InterruptsSnapshot { cpus, interval_secs: None, irqs, softirqs })
+}
+
+// Só o Linux tem /proc; nas demais plataformas a rota responde 501
+pub fn read_interrupts() -> std::io::Result<InterruptsSnapshot> {
+    if !cfg!(target_os = "linux") {
+        return Err(std::io::ErrorKind::Unsupported.into());
+    }
+    read_interrupts_at(Path::new("/proc"))
+}
+
+fn deltas(prev: &[u64], cur: &[u64]) -> Vec<u64> {
+    cur.iter().enumerate().map(|(i, c)| c.saturating_sub(prev.get(i).copied().unwrap_or(0))).collect()
+}
+
+// Snapshot anterior de /proc/interrupts e /proc/softirqs; IRQs e softirqs são casadas pelo nome
+pub struct InterruptsCollector {
+    proc_root: PathBuf,
+    previous: Option<(Instant, InterruptsSnapshot)>,
+}
+
+impl Default for InterruptsCollector {
+    fn default() -> Self {
+        InterruptsCollector::new("/proc")
+    }
+}
+
+impl InterruptsCollector {
+    pub fn new(proc_root: impl Into<PathBuf>) -> Self {
+        InterruptsCollector { proc_root: proc_root.into(), previous: None }
+    }
+
+    // Leitura atual com deltas por CPU e taxa por segundo; na primeira chamada e para IRQs novas (ex.: dispositivo
+    // conectado entre ticks) deltas e taxa ficam None
+    pub fn sample(&mut self) -> std::io::Result<InterruptsSnapshot> {
+        if !cfg!(target_os = "linux") {
+            return Err(std::io::ErrorKind::Unsupported.into());
+        }
+        let mut current = read_interrupts_at(&self.proc_root)?;
+        let now = Instant::now();
+        if let Some((prev_at, prev)) = &self.previous {
+            let elapsed = now.duration_since(*prev_at).as_secs_f64().max(f64::EPSILON);
+            current.interval_secs = Some(elapsed);
+            let prev_irqs: HashMap<&str, &IrqStats> = prev.irqs.iter().map(|i| (i.irq.as_str(), i)).collect();
+            for irq in current.irqs.iter_mut() {
+                if let Some(p) = prev_irqs.get(irq.irq.as_str()) {
+                    irq.rate_per_sec = Some(irq.total.saturating_sub(p.total) as f64 / elapsed);
+                    irq.deltas = Some(deltas(&p.counts, &irq.counts));
+                }
+            }
+            let prev_soft: HashMap<&str, &SoftirqStats> = prev.softirqs.iter().map(|s| (s.name.as_str(), s)).collect();
+            for soft in current.softirqs.iter_mut() {
+                if let Some(p) = prev_soft.get(soft.name.as_str()) {
+                    soft.rate_per_sec = Some(soft.total.saturating_sub(p.total) as f64 / elapsed);
+                    soft.deltas = Some(deltas(&p.counts, &soft.counts));
+                }
+            }
+        }
+        self.previous = Some((now, current.clone()));
+        Ok(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    const INTERRUPTS: &str = "           CPU0       CPU1       CPU2       CPU3\n\
+          0:         40          0          0          0   IO-APIC    2-edge      timer\n\
+          9:          0         12          0          0   IO-APIC    9-fasteoi   acpi\n\
+        124:     105520      20311        944       7712   PCI-MSI 327680-edge      nvme0q0\n\
+        NMI:          3          5          2          1   Non-maskable interrupts\n\
+        LOC:    8812345    7712345    6612345    5512345   Local timer interrupts\n\
+        ERR:          0\n\
+        MIS:          7\n";
+
+    const SOFTIRQS: &str = "                    CPU0       CPU1       CPU2       CPU3\n\
+                  HI:          1          0          0          0\n\
+               TIMER:     100200      90200      80200      70200\n\
+              NET_RX:       5000        600         70          8\n";
+
+    fn proc_root(name: &str) -> TempDir {
+        TempDir::with_files(
+            name,
+            &[
+                ("interrupts", INTERRUPTS),
+                ("softirqs", SOFTIRQS),
+                ("irq/124/smp_affinity", "00000003\n"),
+                ("irq/124/smp_affinity_list", "0-1\n"),
+            ],
+        )
+    }
+
+    #[test]
+    fn parses_ragged_rows_and_descriptions() {
+        let root = proc_root("interrupts");
+        let snapshot = read_interrupts_at(root.path()).unwrap();
+        assert_eq!(snapshot.cpus, vec!["CPU0", "CPU1", "CPU2", "CPU3"]);
+        let irq = |name: &str| snapshot.irqs.iter().find(|i| i.irq == name).unwrap();
+
+        // O "327680" da descrição não entra como contador: já foram lidas as quatro CPUs
+        let nvme = irq("124");
+        assert_eq!(nvme.counts, vec![105520, 20311, 944, 7712]);
+        assert_eq!(nvme.total, 134487);
+        assert_eq!(nvme.description, "PCI-MSI 327680-edge nvme0q0");
+        assert_eq!((nvme.affinity_mask.as_deref(), nvme.affinity_list.as_deref()), (Some("00000003"), Some("0-1")));
+
+        assert_eq!(irq("NMI").description, "Non-maskable interrupts");
+        // IRQ numérica sem arquivos de afinidade e linhas nomeadas não têm afinidade
+        assert!(irq("9").affinity_mask.is_none() && irq("LOC").affinity_list.is_none());
+
+        // ERR e MIS têm um único contador global e nenhuma descrição
+        assert_eq!((irq("ERR").counts.clone(), irq("ERR").total), (vec![0], 0));
+        assert_eq!((irq("MIS").counts.clone(), irq("MIS").description.as_str()), (vec![7], ""));
+
+        let names: Vec<&str> = snapshot.softirqs.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["HI", "TIMER", "NET_RX"]);
+        assert_eq!(snapshot.softirqs[2].total, 5678);
+    }
+
+    #[test]
+    fn softirqs_are_optional() {
+        let root = TempDir::with_files("interrupts-no-softirqs", &[("interrupts", INTERRUPTS)]);
+        let snapshot = read_interrupts_at(root.path()).unwrap();
+        assert_eq!(snapshot.irqs.len(), 7);
+        assert!(snapshot.softirqs.is_empty());
+    }
+
+    #[test]
+    fn deltas_tolerate_ragged_and_reset_counters() {
+        assert_eq!(deltas(&[10, 20, 30, 40], &[15, 20, 25, 50]), vec![5, 0, 0, 10]);
+        // Linha que ganhou colunas (CPU que voltou) conta a partir de zero
+        assert_eq!(deltas(&[7], &[9, 3]), vec![2, 3]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn sample_computes_deltas_by_name() {
+        let root = proc_root("interrupts-sample");
+        let mut collector = InterruptsCollector::new(root.path());
+        let first = collector.sample().unwrap();
+        assert!(first.interval_secs.is_none() && first.irqs.iter().all(|i| i.deltas.is_none()));
+
+        root.write(
+            "interrupts",
+            INTERRUPTS.replace("105520      20311", "105530      20321").replace("MIS:          7", "MIS:          9")
+                + "        125:          4          0          0          0   PCI-MSI 327681-edge      nvme0q1\n",
+        );
+        root.write("softirqs", SOFTIRQS.replace("5000", "5100"));
+        let second = collector.sample().unwrap();
+        assert!(second.interval_secs.is_some());
+        let irq = |name: &str| second.irqs.iter().find(|i| i.irq == name).unwrap();
+        assert_eq!(irq("124").deltas.as_deref(), Some(&[10, 10, 0, 0][..]));
+        assert_eq!(irq("MIS").deltas.as_deref(), Some(&[2][..]));
+        assert!(irq("124").rate_per_sec.unwrap() > 0.0);
+        // IRQ que apareceu entre os ticks ainda não tem base de comparação
+        assert!(irq("125").deltas.is_none() && irq("125").rate_per_sec.is_none());
+        let net_rx = second.softirqs.iter().find(|s| s.name == "NET_RX").unwrap();
+        assert_eq!(net_rx.deltas.as_deref(), Some(&[100, 0, 0, 0][..]));
+    }
+}
@@ -0,0 +1,88 @@
+// Discos e versão do sistema no Linux (sysinfo lê /proc/mounts e /sys/block)
+use crate::fs::DiskInfo;
+use std::path::Path;
+use sysinfo::{DiskKind, Disks, System};
+
+const NETWORK_FS: &[&str] = &["nfs", "nfs4", "cifs", "smb3", "smbfs", "sshfs", "fuse.sshfs", "9p"];
+
+// Arquivos de swap (tipo "file") listados em /proc/swaps
+fn swap_files(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|l| {
+            let mut fields = l.split_whitespace();
+            let path = fields.next()?;
+            (fields.next()? == "file").then(|| path.replace("\\040", " "))
+        })
+        .collect()
+}
+
+// Ponto de montagem que contém o arquivo (o prefixo mais longo)
+fn mount_of<'a>(file: &str, mount_points: &'a [String]) -> Option<&'a str> {
+    mount_points.iter().filter(|m| Path::new(file).starts_with(m.as_str())).max_by_key(|m| m.len()).map(String::as_str)
+}
+
+pub(super) fn read_disks() -> Vec<DiskInfo> {
+    let disks = Disks::new_with_refreshed_list();
+    let mount_points: Vec<String> = disks.list().iter().map(|d| d.mount_point().to_string_lossy().to_string()).collect();
+    let swaps = swap_files(&std::fs::read_to_string("/proc/swaps").unwrap_or_default());
+    let swap_mounts: Vec<&str> = swaps.iter().filter_map(|f| mount_of(f, &mount_points)).collect();
+    disks
+        .list()
+        .iter()
+        .zip(&mount_points)
+        .map(|(disk, mount_point)| {
+            let total = disk.total_space();
+            let free = disk.available_space();
+            let file_system = disk.file_system().to_string_lossy().to_string();
+            let disk_type = if disk.is_removable() {
+                "Removível"
+            } else if NETWORK_FS.contains(&file_system.as_str()) {
+                "Rede"
+            } else if matches!(disk.kind(), DiskKind::HDD | DiskKind::SSD) {
+                "Fixo (HDD/SSD)"
+            } else {
+                "Desconhecido"
+            };
+            let usage = disk.usage();
+            DiskInfo {
+                name: mount_point.clone(),
+                total_bytes: total,
+                free_bytes: free,
+                used_bytes: total.saturating_sub(free),
+                percent_used: if total > 0 { (total.saturating_sub(free) as f32 / total as f32) * 100.0 } else { 0.0 },
+                file_system,
+                is_system: mount_point == "/",
+                has_pagefile: swap_mounts.contains(&mount_point.as_str()),
+                disk_type: disk_type.to_string(),
+                read_bytes: usage.total_read_bytes,
+                write_bytes: usage.total_written_bytes,
+                transfer_bytes: usage.total_read_bytes + usage.total_written_bytes,
+            }
+        })
+        .collect()
+}
+
+// (nome da distribuição, versão, versão do kernel)
+pub(super) fn os_details() -> (String, String, String) {
+    (
+        System::name().unwrap_or_else(|| "Linux".to_string()),
+        System::os_version().unwrap_or_else(|| "Desconhecida".to_string()),
+        System::kernel_version().unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_mount_of_swap_files() {
+        let swaps = "Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority\n/dev/dm-1                               partition\t8388604\t\t0\t\t-2\n/var/swap\\040file                       file\t\t1048572\t\t0\t\t-3\n";
+        assert_eq!(swap_files(swaps), vec!["/var/swap file".to_string()]);
+        let mounts = vec!["/".to_string(), "/var".to_string(), "/var/lib".to_string()];
+        assert_eq!(mount_of("/var/swap file", &mounts), Some("/var"));
+        assert_eq!(mount_of("/swapfile", &mounts), Some("/"));
+    }
+}
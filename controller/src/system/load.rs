@@ -0,0 +1,154 @@
+// Módulo para carga do sistema: load average, fila de execução e Pressure Stall Information
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::path::Path;
+
+#[derive(Serialize, ToSchema, Debug, Clone, Default)]
+pub struct PressureLine {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    pub total_us: u64,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Default)]
+pub struct PressureResource {
+    pub some: Option<PressureLine>,
+    pub full: Option<PressureLine>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Default)]
+pub struct PressureInfo {
+    pub cpu: Option<PressureResource>,
+    pub memory: Option<PressureResource>,
+    pub io: Option<PressureResource>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Default)]
+pub struct LoadInfo {
+    pub load1: f64,
+    pub load5: f64,
+    pub load15: f64,
+    pub runnable_entities: u64,
+    pub total_entities: u64,
+    pub procs_running: Option<u64>,
+    pub procs_blocked: Option<u64>,
+    pub pressure: PressureInfo,
+}
+
+// Função para interpretar /proc/loadavg: "0.52 0.58 0.59 2/1234 5678"
+pub fn parse_loadavg(content: &str) -> Option<LoadInfo> {
+    let f: Vec<&str> = content.split_whitespace().collect();
+    if f.len() < 4 {
+        return None;
+    }
+    let (runnable, total) = f[3].split_once('/')?;
+    Some(LoadInfo {
+        load1: f[0].parse().ok()?,
+        load5: f[1].parse().ok()?,
+        load15: f[2].parse().ok()?,
+        runnable_entities: runnable.parse().ok()?,
+        total_entities: total.parse().ok()?,
+        ..Default::default()
+    })
+}
+
+// Função para extrair procs_running e procs_blocked de /proc/stat
+pub fn parse_proc_stat_tasks(content: &str) -> (Option<u64>, Option<u64>) {
+    let mut running = None;
+    let mut blocked = None;
+    for line in content.lines() {
+        if let Some(v) = line.strip_prefix("procs_running ") {
+            running = v.trim().parse().ok();
+        } else if let Some(v) = line.strip_prefix("procs_blocked ") {
+            blocked = v.trim().parse().ok();
+        }
+    }
+    (running, blocked)
+}
+
+// Função para interpretar /proc/pressure/{cpu,memory,io}:
+// "some avg10=0.00 avg60=0.00 avg300=0.00 total=0"
+pub fn parse_pressure(content: &str) -> PressureResource {
+    let mut resource = PressureResource::default();
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let kind = fields.next();
+        let mut entry = PressureLine::default();
+        for field in fields {
+            match field.split_once('=') {
+                Some(("avg10", v)) => entry.avg10 = v.parse().unwrap_or(0.0),
+                Some(("avg60", v)) => entry.avg60 = v.parse().unwrap_or(0.0),
+                Some(("avg300", v)) => entry.avg300 = v.parse().unwrap_or(0.0),
+                Some(("total", v)) => entry.total_us = v.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+        match kind {
+            Some("some") => resource.some = Some(entry),
+            Some("full") => resource.full = Some(entry),
+            _ => {}
+        }
+    }
+    resource
+}
+
+// Função para ler a carga atual a partir de uma raiz procfs (normalmente "/proc")
+pub fn read_load_at(proc_root: &Path) -> std::io::Result<LoadInfo> {
+    let content = std::fs::read_to_string(proc_root.join("loadavg"))?;
+    let mut load = parse_loadavg(&content).ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "loadavg inválido"))?;
+    if let Ok(stat) = std::fs::read_to_string(proc_root.join("stat")) {
+        (load.procs_running, load.procs_blocked) = parse_proc_stat_tasks(&stat);
+    }
+    // PSI só existe em kernels >= 4.20 com CONFIG_PSI habilitado
+    let pressure = |name: &str| std::fs::read_to_string(proc_root.join("pressure").join(name)).ok().map(|c| parse_pressure(&c));
+    load.pressure = PressureInfo { cpu: pressure("cpu"), memory: pressure("memory"), io: pressure("io") };
+    Ok(load)
+}
+
+// Só o Linux tem /proc; nas demais plataformas a rota responde 501 e o sampler não guarda histórico
+pub fn read_load() -> std::io::Result<LoadInfo> {
+    if !cfg!(target_os = "linux") {
+        return Err(std::io::ErrorKind::Unsupported.into());
+    }
+    read_load_at(Path::new("/proc"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn reads_loadavg_stat_and_pressure() {
+        let proc_root = TempDir::with_files(
+            "load",
+            &[
+                ("loadavg", "0.52 1.08 0.99 3/1234 5678\n"),
+                ("stat", "cpu  1 2 3 4\nctxt 99\nprocs_running 3\nprocs_blocked 1\n"),
+                // Kernels antigos não têm a linha "full" para CPU
+                ("pressure/cpu", "some avg10=1.50 avg60=0.75 avg300=0.10 total=123456\n"),
+                ("pressure/memory", "some avg10=0.00 avg60=0.00 avg300=0.00 total=10\nfull avg10=0.00 avg60=0.00 avg300=0.00 total=5\n"),
+            ],
+        );
+        let load = read_load_at(proc_root.path()).unwrap();
+        assert_eq!((load.load1, load.load5, load.load15), (0.52, 1.08, 0.99));
+        assert_eq!((load.runnable_entities, load.total_entities), (3, 1234));
+        assert_eq!((load.procs_running, load.procs_blocked), (Some(3), Some(1)));
+        let cpu = load.pressure.cpu.unwrap();
+        let some = cpu.some.unwrap();
+        assert_eq!((some.avg10, some.avg60, some.avg300, some.total_us), (1.5, 0.75, 0.1, 123456));
+        assert!(cpu.full.is_none());
+        assert_eq!(load.pressure.memory.unwrap().full.unwrap().total_us, 5);
+        // Sem CONFIG_PSI o arquivo não existe
+        assert!(load.pressure.io.is_none());
+    }
+
+    #[test]
+    fn rejects_malformed_loadavg() {
+        assert!(parse_loadavg("0.52 1.08 0.99").is_none());
+        assert!(parse_loadavg("0.52 1.08 0.99 3-1234 5678").is_none());
+        let proc_root = TempDir::with_files("load-invalid", &[("loadavg", "x y z 1/2 3\n")]);
+        assert_eq!(read_load_at(proc_root.path()).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    }
+}
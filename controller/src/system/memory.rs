@@ -0,0 +1,249 @@
+// Módulo para detalhamento de memória (/proc/meminfo) e swap (/proc/swaps, /proc/vmstat)
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct SwapDevice {
+    pub name: String,
+    pub kind: String,
+    pub size_kb: u64,
+    pub used_kb: u64,
+    pub priority: i32,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Default)]
+pub struct SwapRate {
+    pub swap_in_pages_per_sec: f64,
+    pub swap_out_pages_per_sec: f64,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Default)]
+pub struct MemoryDetails {
+    pub total_kb: u64,
+    pub free_kb: u64,
+    pub available_kb: u64,
+    pub buffers_kb: u64,
+    pub cached_kb: u64,
+    pub shmem_kb: u64,
+    pub slab_kb: u64,
+    pub slab_reclaimable_kb: u64,
+    pub slab_unreclaimable_kb: u64,
+    pub anon_kb: u64,
+    pub dirty_kb: u64,
+    pub writeback_kb: u64,
+    pub committed_as_kb: u64,
+    pub commit_limit_kb: u64,
+    pub hugepages_total: u64,
+    pub hugepages_free: u64,
+    pub hugepage_size_kb: u64,
+    pub swap_total_kb: u64,
+    pub swap_free_kb: u64,
+    pub swap_used_kb: u64,
+    pub swap_cached_kb: u64,
+    pub swap_devices: Vec<SwapDevice>,
+    pub swap_rate: Option<SwapRate>,
+}
+
+// Função para interpretar /proc/meminfo ("Chave:   valor kB")
+pub fn parse_meminfo(content: &str) -> MemoryDetails {
+    let values: HashMap<&str, u64> = content
+        .lines()
+        .filter_map(|line| {
+            let (key, rest) = line.split_once(':')?;
+            Some((key.trim(), rest.split_whitespace().next()?.parse().ok()?))
+        })
+        .collect();
+    let v = |key: &str| values.get(key).copied().unwrap_or(0);
+    let swap_total_kb = v("SwapTotal");
+    let swap_free_kb = v("SwapFree");
+    MemoryDetails {
+        total_kb: v("MemTotal"),
+        free_kb: v("MemFree"),
+        available_kb: v("MemAvailable"),
+        buffers_kb: v("Buffers"),
+        cached_kb: v("Cached"),
+        shmem_kb: v("Shmem"),
+        slab_kb: v("Slab"),
+        slab_reclaimable_kb: v("SReclaimable"),
+        slab_unreclaimable_kb: v("SUnreclaim"),
+        anon_kb: v("AnonPages"),
+        dirty_kb: v("Dirty"),
+        writeback_kb: v("Writeback"),
+        committed_as_kb: v("Committed_AS"),
+        commit_limit_kb: v("CommitLimit"),
+        hugepages_total: v("HugePages_Total"),
+        hugepages_free: v("HugePages_Free"),
+        hugepage_size_kb: v("Hugepagesize"),
+        swap_total_kb,
+        swap_free_kb,
+        swap_used_kb: swap_total_kb.saturating_sub(swap_free_kb),
+        swap_cached_kb: v("SwapCached"),
+        swap_devices: Vec::new(),
+        swap_rate: None,
+    }
+}
+
+// Função para interpretar /proc/swaps (a primeira linha é cabeçalho)
+pub fn parse_swaps(content: &str) -> Vec<SwapDevice> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let f: Vec<&str> = line.split_whitespace().collect();
+            if f.len() < 5 {
+                return None;
+            }
+            Some(SwapDevice {
+                // Espaços no caminho aparecem como "\040"
+                name: f[0].replace("\\040", " "),
+                kind: f[1].to_string(),
+                size_kb: f[2].parse().ok()?,
+                used_kb: f[3].parse().ok()?,
+                priority: f[4].parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+// Função para extrair (pswpin, pswpout) de /proc/vmstat
+pub fn parse_vmstat_swap(content: &str) -> Option<(u64, u64)> {
+    let mut pswpin = None;
+    let mut pswpout = None;
+    for line in content.lines() {
+        match line.split_once(' ') {
+            Some(("pswpin", v)) => pswpin = v.trim().parse().ok(),
+            Some(("pswpout", v)) => pswpout = v.trim().parse().ok(),
+            _ => {}
+        }
+    }
+    Some((pswpin?, pswpout?))
+}
+
+// Função para ler o detalhamento atual a partir de uma raiz procfs (normalmente "/proc")
+pub fn read_memory_at(proc_root: &Path) -> std::io::Result<MemoryDetails> {
+    let mut details = parse_meminfo(&std::fs::read_to_string(proc_root.join("meminfo"))?);
+    if let Ok(swaps) = std::fs::read_to_string(proc_root.join("swaps")) {
+        details.swap_devices = parse_swaps(&swaps);
+    }
+    Ok(details)
+}
+
+// Só o Linux tem /proc; nas demais plataformas a rota responde 501
+pub fn read_memory() -> std::io::Result<MemoryDetails> {
+    if !cfg!(target_os = "linux") {
+        return Err(std::io::ErrorKind::Unsupported.into());
+    }
+    read_memory_at(Path::new("/proc"))
+}
+
+// Contadores pswpin/pswpout (páginas trocadas desde o boot) da leitura anterior do vmstat
+pub struct SwapCollector {
+    proc_root: PathBuf,
+    previous: Option<(Instant, u64, u64)>,
+}
+
+impl Default for SwapCollector {
+    fn default() -> Self {
+        SwapCollector::new("/proc")
+    }
+}
+
+impl SwapCollector {
+    pub fn new(proc_root: impl Into<PathBuf>) -> Self {
+        SwapCollector { proc_root: proc_root.into(), previous: None }
+    }
+
+    // Páginas por segundo entre esta leitura e a anterior; a primeira só guarda os contadores (None).
+    // Um contador menor que o anterior (zerado) conta como zero em vez de estourar
+    pub fn sample(&mut self) -> std::io::Result<Option<SwapRate>> {
+        if !cfg!(target_os = "linux") {
+            return Err(std::io::ErrorKind::Unsupported.into());
+        }
+        let content = std::fs::read_to_string(self.proc_root.join("vmstat"))?;
+        let now = Instant::now();
+        let (pswpin, pswpout) = parse_vmstat_swap(&content).ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "vmstat sem pswpin/pswpout"))?;
+        let rate = self.previous.map(|(prev_at, prev_in, prev_out)| {
+            let elapsed = now.duration_since(prev_at).as_secs_f64().max(f64::EPSILON);
+            SwapRate {
+                swap_in_pages_per_sec: pswpin.saturating_sub(prev_in) as f64 / elapsed,
+                swap_out_pages_per_sec: pswpout.saturating_sub(prev_out) as f64 / elapsed,
+            }
+        });
+        self.previous = Some((now, pswpin, pswpout));
+        Ok(rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    const MEMINFO: &str = "MemTotal:       16314116 kB\n\
+        MemFree:          812344 kB\n\
+        MemAvailable:    9876543 kB\n\
+        Buffers:          204800 kB\n\
+        Cached:          7340032 kB\n\
+        SwapCached:         1024 kB\n\
+        SwapTotal:       2097148 kB\n\
+        SwapFree:        1048572 kB\n\
+        Shmem:            524288 kB\n\
+        Slab:             655360 kB\n\
+        SReclaimable:     409600 kB\n\
+        SUnreclaim:       245760 kB\n\
+        HugePages_Total:       4\n\
+        HugePages_Free:        2\n\
+        Hugepagesize:       2048 kB\n";
+
+    #[test]
+    fn reads_meminfo_and_swaps() {
+        let proc_root = TempDir::with_files(
+            "memory",
+            &[
+                ("meminfo", MEMINFO),
+                (
+                    "swaps",
+                    "Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority\n\
+                     /dev/nvme0n1p3                          partition\t1048572\t\t524288\t\t-2\n\
+                     /var/swap\\040file                       file\t\t1048576\t\t0\t\t10\n",
+                ),
+            ],
+        );
+        let details = read_memory_at(proc_root.path()).unwrap();
+        assert_eq!((details.total_kb, details.free_kb, details.available_kb), (16314116, 812344, 9876543));
+        assert_eq!((details.slab_kb, details.slab_reclaimable_kb, details.slab_unreclaimable_kb), (655360, 409600, 245760));
+        assert_eq!((details.hugepages_total, details.hugepages_free, details.hugepage_size_kb), (4, 2, 2048));
+        assert_eq!((details.swap_total_kb, details.swap_free_kb, details.swap_used_kb), (2097148, 1048572, 1048576));
+        // Chaves ausentes (ex.: kernels sem Committed_AS) ficam em zero
+        assert_eq!(details.committed_as_kb, 0);
+        let names: Vec<&str> = details.swap_devices.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["/dev/nvme0n1p3", "/var/swap file"]);
+        assert_eq!((details.swap_devices[0].used_kb, details.swap_devices[0].priority), (524288, -2));
+        assert_eq!(details.swap_devices[1].kind, "file");
+    }
+
+    #[test]
+    fn reads_swap_counters_from_vmstat() {
+        assert_eq!(parse_vmstat_swap("nr_free_pages 1000\npswpin 42\npswpout 7\npswpin_extra 1\n"), Some((42, 7)));
+        // Sem CONFIG_SWAP os contadores não aparecem
+        assert_eq!(parse_vmstat_swap("nr_free_pages 1000\n"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn swap_rate_needs_two_samples_and_ignores_reset_counters() {
+        let proc_root = TempDir::with_files("swap-rate", &[("vmstat", "pswpin 100\npswpout 50\n")]);
+        let mut collector = SwapCollector::new(proc_root.path());
+        assert!(collector.sample().unwrap().is_none());
+        proc_root.write("vmstat", "pswpin 160\npswpout 50\n");
+        let rate = collector.sample().unwrap().unwrap();
+        assert!(rate.swap_in_pages_per_sec > 0.0);
+        assert_eq!(rate.swap_out_pages_per_sec, 0.0);
+        proc_root.write("vmstat", "pswpin 10\npswpout 5\n");
+        let rate = collector.sample().unwrap().unwrap();
+        assert_eq!((rate.swap_in_pages_per_sec, rate.swap_out_pages_per_sec), (0.0, 0.0));
+    }
+}
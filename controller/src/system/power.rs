@@ -0,0 +1,119 @@
+// Módulo para bateria e fontes de energia (/sys/class/power_supply)
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::path::Path;
+use crate::sysfs::{read_attr, read_flag, read_number};
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct BatteryInfo {
+    pub name: String,
+    pub status: Option<String>,
+    pub capacity_percent: Option<f64>,
+    pub energy_now_wh: Option<f64>,
+    pub energy_full_wh: Option<f64>,
+    pub energy_full_design_wh: Option<f64>,
+    pub health_percent: Option<f64>,
+    pub power_now_w: Option<f64>,
+    pub time_remaining_secs: Option<u64>,
+    pub cycle_count: Option<u64>,
+    pub technology: Option<String>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct PowerInfo {
+    pub ac_online: Option<bool>,
+    pub batteries: Vec<BatteryInfo>,
+}
+
+fn read_battery(dir: &Path, name: String) -> BatteryInfo {
+    let micro = |attr: &str| read_number(&dir.join(attr)).map(|v| v / 1_000_000.0);
+    // Algumas baterias expõem carga (µAh) em vez de energia (µWh); converte usando a tensão
+    let voltage = micro("voltage_min_design").or_else(|| micro("voltage_now"));
+    let energy = |energy_attr: &str, charge_attr: &str| micro(energy_attr).or_else(|| Some(micro(charge_attr)? * voltage?));
+    let energy_now_wh = energy("energy_now", "charge_now");
+    let energy_full_wh = energy("energy_full", "charge_full");
+    let energy_full_design_wh = energy("energy_full_design", "charge_full_design");
+    // Alguns drivers reportam current_now/power_now com sinal (negativo ao descarregar)
+    let power_now_w = micro("power_now").or_else(|| Some(micro("current_now")? * micro("voltage_now")?)).map(f64::abs).filter(|p| *p > 0.0);
+    let status = read_attr(&dir.join("status"));
+    let time_remaining_secs = match (status.as_deref(), energy_now_wh, energy_full_wh, power_now_w) {
+        (Some("Discharging"), Some(now), _, Some(power)) => Some((now / power * 3600.0) as u64),
+        (Some("Charging"), Some(now), Some(full), Some(power)) => Some(((full - now).max(0.0) / power * 3600.0) as u64),
+        _ => None,
+    };
+    BatteryInfo {
+        name,
+        capacity_percent: read_number(&dir.join("capacity")).or_else(|| Some(energy_now_wh? / energy_full_wh? * 100.0)),
+        health_percent: match (energy_full_wh, energy_full_design_wh) {
+            (Some(full), Some(design)) if design > 0.0 => Some(full / design * 100.0),
+            _ => None,
+        },
+        status,
+        energy_now_wh,
+        energy_full_wh,
+        energy_full_design_wh,
+        power_now_w,
+        time_remaining_secs,
+        cycle_count: read_attr(&dir.join("cycle_count")).and_then(|v| v.parse().ok()),
+        technology: read_attr(&dir.join("technology")),
+    }
+}
+
+// Função para ler fontes de energia a partir de uma raiz sysfs; None quando não há bateria
+pub fn read_power_at(sys_root: &Path) -> Option<PowerInfo> {
+    let mut entries: Vec<std::path::PathBuf> = std::fs::read_dir(sys_root.join("class/power_supply")).ok()?.flatten().map(|e| e.path()).collect();
+    entries.sort();
+    let mut ac_online = None;
+    let mut batteries = Vec::new();
+    for dir in entries {
+        let name = dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        match read_attr(&dir.join("type")).as_deref() {
+            Some("Mains") => {
+                let online = read_flag(&dir.join("online"));
+                ac_online = match (ac_online, online) {
+                    (Some(true), _) => Some(true),
+                    (_, o) => o.or(ac_online),
+                };
+            }
+            // Baterias de periféricos (mouse, teclado) têm scope "Device" e não representam o sistema
+            Some("Battery") if read_attr(&dir.join("scope")).as_deref() != Some("Device") => {
+                batteries.push(read_battery(&dir, name));
+            }
+            _ => {}
+        }
+    }
+    if batteries.is_empty() {
+        return None;
+    }
+    Some(PowerInfo { ac_online, batteries })
+}
+
+pub fn read_power() -> Option<PowerInfo> {
+    read_power_at(Path::new("/sys"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn signed_current_still_gives_time_remaining() {
+        let root = TempDir::new("power");
+        for (attr, value) in [
+            ("type", "Battery"),
+            ("status", "Discharging"),
+            ("charge_now", "2000000"),
+            ("charge_full", "4000000"),
+            ("voltage_now", "10000000"),
+            ("current_now", "-1000000"),
+        ] {
+            root.write(format!("class/power_supply/BAT0/{}", attr), value);
+        }
+        let power = read_power_at(root.path()).unwrap();
+        let battery = &power.batteries[0];
+        // 20 Wh restantes a 10 W
+        assert_eq!(battery.power_now_w, Some(10.0));
+        assert_eq!(battery.time_remaining_secs, Some(7200));
+    }
+}
@@ -0,0 +1,212 @@
+// Módulo para sensores de hardware (/sys/class/hwmon e /sys/class/thermal)
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::path::Path;
+use crate::sysfs::{read_attr, read_number};
+
+#[derive(Serialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SensorKind {
+    Temperature,
+    Fan,
+    Voltage,
+    Power,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SensorStatus {
+    Ok,
+    OverMax,
+    Critical,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct SensorReading {
+    pub source: String,
+    pub chip: String,
+    pub kind: SensorKind,
+    pub label: String,
+    pub value: f64,
+    pub unit: String,
+    pub max: Option<f64>,
+    pub critical: Option<f64>,
+    pub status: SensorStatus,
+}
+
+fn status_for(value: f64, max: Option<f64>, critical: Option<f64>) -> SensorStatus {
+    if critical.is_some_and(|c| value >= c) {
+        SensorStatus::Critical
+    } else if max.is_some_and(|m| value >= m) {
+        SensorStatus::OverMax
+    } else {
+        SensorStatus::Ok
+    }
+}
+
+// Prefixo do atributo, fator de escala para a unidade exibida e unidade
+const HWMON_TYPES: &[(&str, SensorKind, f64, &str)] = &[
+    ("temp", SensorKind::Temperature, 1000.0, "°C"),
+    ("fan", SensorKind::Fan, 1.0, "RPM"),
+    ("in", SensorKind::Voltage, 1000.0, "V"),
+    ("power", SensorKind::Power, 1_000_000.0, "W"),
+];
+
+fn read_hwmon_chip(dir: &Path, readings: &mut Vec<SensorReading>) {
+    let chip = read_attr(&dir.join("name")).unwrap_or_else(|| dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default());
+    let mut inputs: Vec<String> = std::fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|e| e.file_name().to_string_lossy().to_string()).filter(|n| n.ends_with("_input")).collect())
+        .unwrap_or_default();
+    inputs.sort();
+    for input in inputs {
+        let base = input.trim_end_matches("_input");
+        let Some(&(prefix, kind, scale, unit)) = HWMON_TYPES.iter().find(|(p, ..)| base.strip_prefix(p).is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()))) else {
+            continue;
+        };
+        let Some(raw) = read_number(&dir.join(&input)) else { continue };
+        let attr = |suffix: &str| read_number(&dir.join(format!("{}_{}", base, suffix))).map(|v| v / scale);
+        let value = raw / scale;
+        let max = attr("max").or_else(|| if prefix == "power" { attr("cap") } else { None });
+        let critical = attr("crit");
+        readings.push(SensorReading {
+            source: "hwmon".to_string(),
+            chip: chip.clone(),
+            kind,
+            label: read_attr(&dir.join(format!("{}_label", base))).unwrap_or_else(|| base.to_string()),
+            value,
+            unit: unit.to_string(),
+            max,
+            critical,
+            status: status_for(value, max, critical),
+        });
+    }
+}
+
+fn read_thermal_zone(dir: &Path, readings: &mut Vec<SensorReading>) {
+    let Some(temp) = read_number(&dir.join("temp")) else { return };
+    let zone = dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let mut max = None;
+    let mut critical = None;
+    // Pontos de disparo: "critical" desliga a máquina, "hot" marca o limite de alerta
+    for i in 0.. {
+        let Some(kind) = read_attr(&dir.join(format!("trip_point_{}_type", i))) else { break };
+        let trip = read_number(&dir.join(format!("trip_point_{}_temp", i))).map(|t| t / 1000.0);
+        match kind.as_str() {
+            "critical" => critical = trip,
+            "hot" => max = trip,
+            _ => {}
+        }
+    }
+    let value = temp / 1000.0;
+    readings.push(SensorReading {
+        source: "thermal".to_string(),
+        chip: zone,
+        kind: SensorKind::Temperature,
+        label: read_attr(&dir.join("type")).unwrap_or_default(),
+        value,
+        unit: "°C".to_string(),
+        max,
+        critical,
+        status: status_for(value, max, critical),
+    });
+}
+
+fn sorted_entries(dir: &Path, prefix: &str) -> Vec<std::path::PathBuf> {
+    let mut entries: Vec<std::path::PathBuf> = std::fs::read_dir(dir)
+        .map(|e| e.flatten().filter(|e| e.file_name().to_string_lossy().starts_with(prefix)).map(|e| e.path()).collect())
+        .unwrap_or_default();
+    entries.sort();
+    entries
+}
+
+// Função para ler todos os sensores a partir de uma raiz sysfs (normalmente "/sys")
+pub fn read_sensors_at(sys_root: &Path) -> Vec<SensorReading> {
+    let mut readings = Vec::new();
+    for chip in sorted_entries(&sys_root.join("class/hwmon"), "hwmon") {
+        read_hwmon_chip(&chip, &mut readings);
+    }
+    for zone in sorted_entries(&sys_root.join("class/thermal"), "thermal_zone") {
+        read_thermal_zone(&zone, &mut readings);
+    }
+    readings
+}
+
+pub fn read_sensors() -> Vec<SensorReading> {
+    read_sensors_at(Path::new("/sys"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    fn find<'a>(readings: &'a [SensorReading], label: &str) -> &'a SensorReading {
+        readings.iter().find(|r| r.label == label).unwrap()
+    }
+
+    #[test]
+    fn parses_hwmon_temp_fan_and_voltage() {
+        let root = TempDir::with_files(
+            "hwmon",
+            &[
+                ("class/hwmon/hwmon0/name", "coretemp\n"),
+                ("class/hwmon/hwmon0/temp1_input", "45000\n"),
+                ("class/hwmon/hwmon0/temp1_label", "Package id 0\n"),
+                ("class/hwmon/hwmon0/temp1_max", "80000\n"),
+                ("class/hwmon/hwmon0/temp1_crit", "100000\n"),
+                ("class/hwmon/hwmon0/temp2_input", "85000\n"),
+                ("class/hwmon/hwmon0/temp2_max", "80000\n"),
+                ("class/hwmon/hwmon0/temp2_crit", "100000\n"),
+                ("class/hwmon/hwmon0/temp3_input", "101000\n"),
+                ("class/hwmon/hwmon0/temp3_crit", "100000\n"),
+                ("class/hwmon/hwmon1/name", "nct6775\n"),
+                ("class/hwmon/hwmon1/fan1_input", "1200\n"),
+                ("class/hwmon/hwmon1/in0_input", "1250\n"),
+                ("class/hwmon/hwmon1/in0_label", "Vcore\n"),
+                // Atributos sem _input ou de tipo desconhecido são ignorados
+                ("class/hwmon/hwmon1/fan1_min", "300\n"),
+                ("class/hwmon/hwmon1/intrusion0_input", "0\n"),
+            ],
+        );
+        let readings = read_sensors_at(root.path());
+        assert_eq!(readings.len(), 5);
+
+        let package = find(&readings, "Package id 0");
+        assert_eq!((package.chip.as_str(), package.kind, package.value, package.unit.as_str()), ("coretemp", SensorKind::Temperature, 45.0, "°C"));
+        assert_eq!((package.max, package.critical, package.status), (Some(80.0), Some(100.0), SensorStatus::Ok));
+        // Sem _label o rótulo é o nome base do atributo
+        assert_eq!(find(&readings, "temp2").status, SensorStatus::OverMax);
+        assert_eq!(find(&readings, "temp3").status, SensorStatus::Critical);
+
+        let fan = find(&readings, "fan1");
+        assert_eq!((fan.kind, fan.value, fan.unit.as_str(), fan.max), (SensorKind::Fan, 1200.0, "RPM", None));
+        let vcore = find(&readings, "Vcore");
+        assert_eq!((vcore.kind, vcore.value, vcore.unit.as_str()), (SensorKind::Voltage, 1.25, "V"));
+    }
+
+    #[test]
+    fn thermal_zone_uses_trip_points() {
+        let root = TempDir::with_files(
+            "thermal",
+            &[
+                ("class/thermal/thermal_zone0/type", "acpitz\n"),
+                ("class/thermal/thermal_zone0/temp", "92000\n"),
+                ("class/thermal/thermal_zone0/trip_point_0_type", "passive\n"),
+                ("class/thermal/thermal_zone0/trip_point_0_temp", "70000\n"),
+                ("class/thermal/thermal_zone0/trip_point_1_type", "hot\n"),
+                ("class/thermal/thermal_zone0/trip_point_1_temp", "90000\n"),
+                ("class/thermal/thermal_zone0/trip_point_2_type", "critical\n"),
+                ("class/thermal/thermal_zone0/trip_point_2_temp", "105000\n"),
+            ],
+        );
+        let readings = read_sensors_at(root.path());
+        let zone = find(&readings, "acpitz");
+        assert_eq!((zone.source.as_str(), zone.value, zone.max, zone.critical), ("thermal", 92.0, Some(90.0), Some(105.0)));
+        assert_eq!(zone.status, SensorStatus::OverMax);
+    }
+
+    #[test]
+    fn missing_tree_yields_nothing() {
+        assert!(read_sensors_at(Path::new("/nonexistent-sysfs")).is_empty());
+    }
+}
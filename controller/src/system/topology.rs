@@ -0,0 +1,318 @@
+// Módulo para topologia da CPU (sysfs) e flags de ISA (CPUID via raw-cpuid)
+use serde::Serialize;
+use utoipa::ToSchema;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tokio::task;
+use crate::sysfs::read_attr;
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct LogicalCpu {
+    pub cpu: u32,
+    pub socket: Option<u32>,
+    pub core_id: Option<u32>,
+    pub numa_node: Option<u32>,
+    pub thread_siblings: Option<String>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct NumaNode {
+    pub id: u32,
+    pub cpus: String,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct CacheInfo {
+    pub level: u32,
+    pub kind: String,
+    pub size_kb: u64,
+    pub instances: usize,
+    pub cpus_per_instance: usize,
+    pub shared_cpu_lists: Vec<String>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone)]
+pub struct CpuTopology {
+    pub vendor: Option<String>,
+    pub brand: Option<String>,
+    pub logical_cpus: usize,
+    pub physical_cores: usize,
+    pub sockets: usize,
+    pub threads_per_core: usize,
+    pub microcode: Option<String>,
+    pub numa_nodes: Vec<NumaNode>,
+    pub caches: Vec<CacheInfo>,
+    pub cpus: Vec<LogicalCpu>,
+    pub features: Vec<String>,
+}
+
+fn numbered_entries(dir: &Path, prefix: &str) -> Vec<u32> {
+    let mut ids: Vec<u32> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| e.file_name().to_str().and_then(|n| n.strip_prefix(prefix)).and_then(|n| n.parse().ok()))
+                .collect()
+        })
+        .unwrap_or_default();
+    ids.sort();
+    ids
+}
+
+// Tamanho de cache no formato do sysfs: "48K", "2048K", "32M"
+fn parse_cache_size(raw: &str) -> Option<u64> {
+    let raw = raw.trim();
+    if let Some(v) = raw.strip_suffix('K') {
+        v.parse().ok()
+    } else if let Some(v) = raw.strip_suffix('M') {
+        v.parse::<u64>().ok().map(|v| v * 1024)
+    } else {
+        raw.parse().ok()
+    }
+}
+
+// Função para ler a topologia a partir de uma raiz sysfs (normalmente "/sys")
+pub fn read_topology_at(sys_root: &Path) -> (Vec<LogicalCpu>, Vec<NumaNode>, Vec<CacheInfo>) {
+    let cpu_dir = sys_root.join("devices/system/cpu");
+    let node_dir = sys_root.join("devices/system/node");
+
+    let mut cpu_node: BTreeMap<u32, u32> = BTreeMap::new();
+    let numa_nodes: Vec<NumaNode> = numbered_entries(&node_dir, "node")
+        .into_iter()
+        .filter_map(|id| {
+            let cpus = read_attr(&node_dir.join(format!("node{}/cpulist", id)))?;
+            for cpu in expand_cpu_list(&cpus) {
+                cpu_node.insert(cpu, id);
+            }
+            Some(NumaNode { id, cpus })
+        })
+        .collect();
+
+    // Cada cache compartilhado aparece em todas as CPUs que o usam; a lista de CPUs identifica a instância
+    let mut caches: BTreeMap<(u32, String, String), u64> = BTreeMap::new();
+    let cpus: Vec<LogicalCpu> = numbered_entries(&cpu_dir, "cpu")
+        .into_iter()
+        .map(|cpu| {
+            let base = cpu_dir.join(format!("cpu{}", cpu));
+            for index in numbered_entries(&base.join("cache"), "index") {
+                let idx = base.join(format!("cache/index{}", index));
+                let (Some(level), Some(kind), Some(size), Some(shared)) = (
+                    read_attr(&idx.join("level")).and_then(|l| l.parse::<u32>().ok()),
+                    read_attr(&idx.join("type")),
+                    read_attr(&idx.join("size")).and_then(|s| parse_cache_size(&s)),
+                    read_attr(&idx.join("shared_cpu_list")),
+                ) else {
+                    continue;
+                };
+                caches.insert((level, kind, shared), size);
+            }
+            let topo = base.join("topology");
+            LogicalCpu {
+                cpu,
+                socket: read_attr(&topo.join("physical_package_id")).and_then(|v| v.parse().ok()),
+                core_id: read_attr(&topo.join("core_id")).and_then(|v| v.parse().ok()),
+                numa_node: cpu_node.get(&cpu).copied(),
+                thread_siblings: read_attr(&topo.join("thread_siblings_list")),
+            }
+        })
+        .collect();
+
+    // Consolida instâncias iguais (ex.: um L2 por núcleo) numa única linha com contagem
+    let mut grouped: BTreeMap<(u32, String, u64), CacheInfo> = BTreeMap::new();
+    for ((level, kind, shared), size_kb) in caches {
+        let info = grouped.entry((level, kind.clone(), size_kb)).or_insert(CacheInfo {
+            level,
+            kind,
+            size_kb,
+            instances: 0,
+            cpus_per_instance: expand_cpu_list(&shared).len(),
+            shared_cpu_lists: Vec::new(),
+        });
+        info.instances += 1;
+        info.shared_cpu_lists.push(shared);
+    }
+    (cpus, numa_nodes, grouped.into_values().collect())
+}
+
+// Expande listas do sysfs como "0-3,8-11" em CPUs individuais
+pub fn expand_cpu_list(list: &str) -> Vec<u32> {
+    list.split(',')
+        .filter_map(|part| {
+            let part = part.trim();
+            match part.split_once('-') {
+                Some((a, b)) => Some((a.parse().ok()?..=b.parse().ok()?).collect::<Vec<u32>>()),
+                None => part.parse().ok().map(|v| vec![v]),
+            }
+        })
+        .flatten()
+        .collect()
+}
+
+fn read_microcode(sys_root: &Path, proc_root: &Path) -> Option<String> {
+    read_attr(&sys_root.join("devices/system/cpu/cpu0/microcode/version")).or_else(|| {
+        std::fs::read_to_string(proc_root.join("cpuinfo"))
+            .ok()?
+            .lines()
+            .find(|l| l.starts_with("microcode"))
+            .and_then(|l| l.split_once(':'))
+            .map(|(_, v)| v.trim().to_string())
+    })
+}
+
+// Função para listar as extensões de ISA suportadas segundo a instrução CPUID
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn cpuid_features() -> (Option<String>, Option<String>, Vec<String>) {
+    features_from(raw_cpuid::CpuId::new(), read_xcr0)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn read_xcr0() -> u64 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::_xgetbv;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::_xgetbv;
+    // Só chamada com OSXSAVE ligado: sem ele a instrução XGETBV gera #UD
+    unsafe { _xgetbv(0) }
+}
+
+// Bits de XCR0 que o sistema operacional liga quando salva o estado dos registradores na troca de contexto
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const XCR0_AVX: u64 = 0b110; // SSE (XMM) e AVX (YMM)
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const XCR0_AVX512: u64 = 0b1110_0000; // opmask, ZMM0-15 (metade alta) e ZMM16-31
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const XCR0_AMX: u64 = 0b11 << 17; // XTILECFG e XTILEDATA
+
+// A CPU pode anunciar AVX/AVX-512/AMX sem que o SO salve os registradores (ex.: kernel com noxsave, hipervisor
+// que esconde o estado); usar as instruções nesse caso falha, então só são listadas com o bit correspondente em XCR0
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn features_from<R: raw_cpuid::CpuIdReader>(cpuid: raw_cpuid::CpuId<R>, xcr0: impl FnOnce() -> u64) -> (Option<String>, Option<String>, Vec<String>) {
+    let vendor = cpuid.get_vendor_info().map(|v| v.as_str().to_string());
+    let brand = cpuid.get_processor_brand_string().map(|b| b.as_str().trim().to_string());
+    let feature_info = cpuid.get_feature_info();
+    let xcr0 = if feature_info.as_ref().is_some_and(|f| f.has_oxsave()) { xcr0() } else { 0 };
+    let os_avx = xcr0 & XCR0_AVX == XCR0_AVX;
+    let os_avx512 = os_avx && xcr0 & XCR0_AVX512 == XCR0_AVX512;
+    let os_amx = xcr0 & XCR0_AMX == XCR0_AMX;
+    let mut features = Vec::new();
+    let mut add = |name: &str, present: bool| {
+        if present {
+            features.push(name.to_string());
+        }
+    };
+    if let Some(f) = feature_info {
+        add("sse", f.has_sse());
+        add("sse2", f.has_sse2());
+        add("sse3", f.has_sse3());
+        add("ssse3", f.has_ssse3());
+        add("sse4_1", f.has_sse41());
+        add("sse4_2", f.has_sse42());
+        add("popcnt", f.has_popcnt());
+        add("aes", f.has_aesni());
+        add("pclmulqdq", f.has_pclmulqdq());
+        add("avx", os_avx && f.has_avx());
+        add("f16c", os_avx && f.has_f16c());
+        add("fma", os_avx && f.has_fma());
+        add("rdrand", f.has_rdrand());
+        add("hypervisor", f.has_hypervisor());
+    }
+    if let Some(e) = cpuid.get_extended_feature_info() {
+        add("avx2", os_avx && e.has_avx2());
+        add("bmi1", e.has_bmi1());
+        add("bmi2", e.has_bmi2());
+        add("adx", e.has_adx());
+        add("sha", e.has_sha());
+        add("rdseed", e.has_rdseed());
+        add("vaes", os_avx && e.has_vaes());
+        add("vpclmulqdq", os_avx && e.has_vpclmulqdq());
+        add("gfni", e.has_gfni());
+        add("avx512f", os_avx512 && e.has_avx512f());
+        add("avx512dq", os_avx512 && e.has_avx512dq());
+        add("avx512cd", os_avx512 && e.has_avx512cd());
+        add("avx512bw", os_avx512 && e.has_avx512bw());
+        add("avx512vl", os_avx512 && e.has_avx512vl());
+        add("avx512ifma", os_avx512 && e.has_avx512_ifma());
+        add("avx512vbmi", os_avx512 && e.has_avx512vbmi());
+        add("avx512vbmi2", os_avx512 && e.has_avx512vbmi2());
+        add("avx512vnni", os_avx512 && e.has_avx512vnni());
+        add("avx512bitalg", os_avx512 && e.has_avx512bitalg());
+        add("avx512vpopcntdq", os_avx512 && e.has_avx512vpopcntdq());
+        add("avx512bf16", os_avx512 && e.has_avx512_bf16());
+        add("avx512fp16", os_avx512 && e.has_avx512_fp16());
+        add("avx_vnni", os_avx && e.has_avx_vnni());
+        add("amx_tile", os_amx && e.has_amx_tile());
+    }
+    (vendor, brand, features)
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+pub fn cpuid_features() -> (Option<String>, Option<String>, Vec<String>) {
+    (None, None, Vec::new())
+}
+
+// Função para montar a visão completa da CPU
+pub async fn get_cpu_topology() -> Result<CpuTopology, anyhow::Error> {
+    let topology = task::spawn_blocking(|| {
+        let (cpus, numa_nodes, caches) = read_topology_at(Path::new("/sys"));
+        let (vendor, brand, features) = cpuid_features();
+        // Sem sysfs (ex.: Windows) recorre às contagens do num_cpus
+        let sockets = cpus.iter().filter_map(|c| c.socket).collect::<BTreeSet<_>>().len().max(1);
+        let physical_cores = match cpus.iter().filter_map(|c| Some((c.socket?, c.core_id?))).collect::<BTreeSet<_>>().len() {
+            0 => num_cpus::get_physical(),
+            n => n,
+        };
+        let logical_cpus = if cpus.is_empty() { num_cpus::get() } else { cpus.len() };
+        CpuTopology {
+            vendor,
+            brand,
+            logical_cpus,
+            physical_cores,
+            sockets,
+            threads_per_core: (logical_cpus / physical_cores.max(1)).max(1),
+            microcode: read_microcode(Path::new("/sys"), Path::new("/proc")),
+            numa_nodes,
+            caches,
+            cpus,
+            features,
+        }
+    }).await?;
+    Ok(topology)
+}
+
+#[cfg(all(test, any(target_arch = "x86", target_arch = "x86_64")))]
+mod tests {
+    use super::*;
+    use raw_cpuid::{CpuId, CpuIdResult};
+
+    // Leaf 1: SSE/SSE2 (EDX 25/26), SSE3 (ECX 0), FMA (ECX 12), OSXSAVE (ECX 27), AVX (ECX 28).
+    // Leaf 7: AVX2 (EBX 5), AVX512F (EBX 16), AVX512DQ (EBX 17)
+    fn mocked_cpu(osxsave: bool) -> CpuId<impl raw_cpuid::CpuIdReader> {
+        CpuId::with_cpuid_fn(move |eax: u32, _ecx: u32| match eax {
+            // "GenuineIntel" em EBX, EDX, ECX
+            0 => CpuIdResult { eax: 7, ebx: 0x756e_6547, ecx: 0x6c65_746e, edx: 0x4965_6e69 },
+            1 => CpuIdResult { eax: 0, ebx: 0, ecx: 1 | 1 << 12 | u32::from(osxsave) << 27 | 1 << 28, edx: 1 << 25 | 1 << 26 },
+            7 => CpuIdResult { eax: 0, ebx: 1 << 5 | 1 << 16 | 1 << 17, ecx: 0, edx: 0 },
+            _ => CpuIdResult { eax: 0, ebx: 0, ecx: 0, edx: 0 },
+        })
+    }
+
+    fn features(osxsave: bool, xcr0: u64) -> Vec<String> {
+        features_from(mocked_cpu(osxsave), || xcr0).2
+    }
+
+    #[test]
+    fn avx_needs_os_support_in_xcr0() {
+        let base = ["sse", "sse2", "sse3"];
+        // SO salva XMM, YMM e o estado do AVX-512
+        let all = features(true, 0b1110_0111);
+        assert_eq!(all, [&base[..], &["avx", "fma", "avx2", "avx512f", "avx512dq"]].concat());
+        // Só XMM/YMM: AVX sim, AVX-512 não
+        assert_eq!(features(true, 0b111), [&base[..], &["avx", "fma", "avx2"]].concat());
+        // Estado do AVX-512 sem YMM não basta
+        assert_eq!(features(true, 0b1110_0011), base.to_vec());
+        // Sem OSXSAVE o XCR0 nem é lido
+        let (vendor, _, without) = features_from(mocked_cpu(false), || panic!("XGETBV sem OSXSAVE"));
+        assert_eq!(vendor.as_deref(), Some("GenuineIntel"));
+        assert_eq!(without, base.to_vec());
+    }
+}
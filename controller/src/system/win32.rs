@@ -0,0 +1,146 @@
+// Discos e versão do sistema pelas APIs do Windows
+use crate::fs::DiskInfo;
+use std::os::windows::ffi::OsStrExt;
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn GetLogicalDrives() -> u32;
+    fn GetDriveTypeW(lpRootPathName: *const u16) -> u32;
+    fn GetDiskFreeSpaceExW(
+        lpDirectoryName: *const u16,
+        lpFreeBytesAvailable: *mut u64,
+        lpTotalNumberOfBytes: *mut u64,
+        lpTotalNumberOfFreeBytes: *mut u64,
+    ) -> i32;
+}
+
+fn get_volume_file_system(path: &str) -> Option<String> {
+    use std::ptr::null_mut;
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetVolumeInformationW(
+            lpRootPathName: *const u16,
+            lpVolumeNameBuffer: *mut u16,
+            nVolumeNameSize: u32,
+            lpVolumeSerialNumber: *mut u32,
+            lpMaximumComponentLength: *mut u32,
+            lpFileSystemFlags: *mut u32,
+            lpFileSystemNameBuffer: *mut u16,
+            nFileSystemNameSize: u32,
+        ) -> i32;
+    }
+    let mut fs_buf = [0u16; 32];
+    let wpath: Vec<u16> = std::ffi::OsStr::new(path).encode_wide().chain(Some(0)).collect();
+    let ok = unsafe {
+        GetVolumeInformationW(
+            wpath.as_ptr(),
+            null_mut(),
+            0,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            fs_buf.as_mut_ptr(),
+            fs_buf.len() as u32,
+        )
+    };
+    if ok != 0 {
+        let fs = String::from_utf16_lossy(&fs_buf);
+        Some(fs.trim_end_matches('\u{0}').to_string())
+    } else {
+        None
+    }
+}
+
+fn is_system_drive(path: &str) -> bool {
+    path.to_uppercase().starts_with(r"C:\")
+}
+
+fn has_pagefile_on_drive(path: &str) -> bool {
+    let pagefile = format!("{}pagefile.sys", path);
+    std::path::Path::new(&pagefile).exists()
+}
+
+fn get_disk_type(drive_type: u32) -> String {
+    match drive_type {
+        2 => "Removível".to_string(),
+        3 => "Fixo (HDD/SSD)".to_string(),
+        4 => "Rede".to_string(),
+        5 => "CD/DVD".to_string(),
+        6 => "RAM Disk".to_string(),
+        _ => "Desconhecido".to_string(),
+    }
+}
+
+fn get_disk_stats_wmi(drive_letter: &str) -> (u64, u64, u64) {
+    // Tenta obter IO nativo via DeviceIoControl/IOCTL_DISK_PERFORMANCE; sem acesso ao volume fica zerado
+    match crate::fs::diskperf::query(drive_letter) {
+        Some(perf) => (perf.BytesRead as u64, perf.BytesWritten as u64, (perf.BytesRead + perf.BytesWritten) as u64),
+        None => (0, 0, 0),
+    }
+}
+
+// Discos detalhados (abordagem FFI/WinAPI)
+pub(super) fn read_disks() -> Vec<DiskInfo> {
+    let mut disks = Vec::new();
+    let drives = unsafe { GetLogicalDrives() };
+    for i in 0..26 {
+        if (drives & (1 << i)) != 0 {
+            let letter = (b'A' + i as u8) as char;
+            let path = format!(r"{}:\", letter);
+            let wpath: Vec<u16> = std::ffi::OsStr::new(&path).encode_wide().chain(Some(0)).collect();
+            let drive_type = unsafe { GetDriveTypeW(wpath.as_ptr()) };
+            if drive_type < 2 { continue; }
+            let mut free = 0u64;
+            let mut total = 0u64;
+            let mut total_free = 0u64;
+            let _ = unsafe { GetDiskFreeSpaceExW(wpath.as_ptr(), &mut free, &mut total, &mut total_free) };
+            let file_system = get_volume_file_system(&path).unwrap_or_else(|| "Desconhecido".to_string());
+            let is_system = is_system_drive(&path);
+            let has_pagefile = has_pagefile_on_drive(&path);
+            let disk_type = get_disk_type(drive_type);
+            let (read_bytes, write_bytes, transfer_bytes) = get_disk_stats_wmi(&letter.to_string());
+            disks.push(DiskInfo {
+                name: path.clone(),
+                total_bytes: total,
+                free_bytes: total_free,
+                used_bytes: total.saturating_sub(total_free),
+                percent_used: if total > 0 { ((total - total_free) as f32 / total as f32) * 100.0 } else { 0.0 },
+                file_system,
+                is_system,
+                has_pagefile,
+                disk_type,
+                read_bytes,
+                write_bytes,
+                transfer_bytes,
+            });
+        }
+    }
+    disks
+}
+
+// (nome, versão, build) do sistema operacional
+pub(super) fn os_details() -> (String, String, String) {
+    let os_version = {
+        #[repr(C)]
+        #[allow(non_snake_case, clippy::upper_case_acronyms)]
+        struct OSVERSIONINFOW {
+            dwOSVersionInfoSize: u32,
+            dwMajorVersion: u32,
+            dwMinorVersion: u32,
+            dwBuildNumber: u32,
+            dwPlatformId: u32,
+            szCSDVersion: [u16; 128],
+        }
+        extern "system" {
+            fn GetVersionExW(lpVersionInformation: *mut OSVERSIONINFOW) -> i32;
+        }
+        let mut info = unsafe { std::mem::MaybeUninit::<OSVERSIONINFOW>::zeroed().assume_init() };
+        info.dwOSVersionInfoSize = std::mem::size_of::<OSVERSIONINFOW>() as u32;
+        if unsafe { GetVersionExW(&mut info) } != 0 {
+            format!("{}.{}.{}", info.dwMajorVersion, info.dwMinorVersion, info.dwBuildNumber)
+        } else {
+            "Desconhecida".to_string()
+        }
+    };
+    ("Windows".to_string(), os_version, "Windows".to_string())
+}
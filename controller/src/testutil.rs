@@ -0,0 +1,46 @@
+// Utilitários compartilhados pelos testes
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Diretório temporário exclusivo de um teste, apagado ao sair de escopo (mesmo se o teste falhar)
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        // PID + contador: testes rodam em paralelo e uma execução interrompida pode ter deixado restos
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!("controller-{}-{}-{}", std::process::id(), NEXT.fetch_add(1, Ordering::Relaxed), name));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        TempDir { path }
+    }
+
+    // Monta uma árvore de arquivos (caminhos relativos à raiz), útil para raízes procfs/sysfs falsas
+    pub fn with_files(name: &str, files: &[(&str, &str)]) -> Self {
+        let dir = TempDir::new(name);
+        for (path, content) in files {
+            dir.write(path, content);
+        }
+        dir
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // Grava o arquivo criando os diretórios intermediários e devolve o caminho completo
+    pub fn write(&self, path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> PathBuf {
+        let path = self.path.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
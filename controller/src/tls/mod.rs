@@ -0,0 +1,197 @@
+// Módulo de TLS: carrega certificado e chave (e a CA dos clientes para mTLS) e recarrega no SIGHUP ou quando os arquivos mudam
+use anyhow::{bail, Context};
+use axum_server::tls_rustls::RustlsConfig;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::RootCertStore;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::config::TlsConfig;
+
+fn read_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>, anyhow::Error> {
+    let certs = CertificateDer::pem_file_iter(path)
+        .with_context(|| format!("não foi possível ler {}", path.display()))?
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("PEM inválido em {}", path.display()))?;
+    if certs.is_empty() {
+        bail!("nenhum certificado encontrado em {}", path.display());
+    }
+    Ok(certs)
+}
+
+// Função para montar a configuração do rustls a partir dos arquivos PEM
+pub fn server_config(tls: &TlsConfig) -> Result<rustls::ServerConfig, anyhow::Error> {
+    let certs = read_certs(&tls.cert)?;
+    let key = PrivateKeyDer::from_pem_file(&tls.key).with_context(|| format!("chave privada inválida em {}", tls.key.display()))?;
+    let builder = rustls::ServerConfig::builder();
+    // Com client_ca configurada, apenas clientes com certificado assinado por ela conseguem conectar
+    let builder = match &tls.client_ca {
+        Some(ca) => {
+            let mut roots = RootCertStore::empty();
+            for cert in read_certs(ca)? {
+                roots.add(cert).with_context(|| format!("certificado de CA inválido em {}", ca.display()))?;
+            }
+            builder.with_client_cert_verifier(WebPkiClientVerifier::builder(Arc::new(roots)).build()?)
+        }
+        None => builder.with_no_client_auth(),
+    };
+    let mut config = builder
+        .with_single_cert(certs, key)
+        .with_context(|| format!("certificado {} e chave {} não formam um par válido", tls.cert.display(), tls.key.display()))?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(config)
+}
+
+pub fn load(tls: &TlsConfig) -> Result<RustlsConfig, anyhow::Error> {
+    Ok(RustlsConfig::from_config(Arc::new(server_config(tls)?)))
+}
+
+// Troca a configuração só se os arquivos novos forem válidos; só novas conexões usam o certificado novo
+fn reload(tls: &TlsConfig, rustls: &RustlsConfig) -> Result<(), anyhow::Error> {
+    rustls.reload_from_config(Arc::new(server_config(tls)?));
+    Ok(())
+}
+
+fn reload_and_log(tls: &TlsConfig, rustls: &RustlsConfig) {
+    match reload(tls, rustls) {
+        Ok(()) => tracing::info!(cert = %tls.cert.display(), "certificado TLS recarregado"),
+        Err(e) => tracing::error!(error = format!("{:#}", e), "falha ao recarregar TLS, mantendo o certificado atual"),
+    }
+}
+
+// Relê os arquivos a cada SIGHUP; as conexões abertas continuam
+#[cfg(unix)]
+pub fn spawn_reloader(tls: TlsConfig, rustls: RustlsConfig) {
+    use tokio::signal::unix::{signal, SignalKind};
+    tokio::spawn(async move {
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                tracing::error!(error = %e, "não foi possível instalar o handler de SIGHUP");
+                return;
+            }
+        };
+        while hangup.recv().await.is_some() {
+            reload_and_log(&tls, &rustls);
+        }
+    });
+}
+
+#[cfg(not(unix))]
+const WATCH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+// Sem SIGHUP no Windows: confere periodicamente a data de modificação dos arquivos e relê quando alguma muda
+#[cfg(not(unix))]
+pub fn spawn_reloader(tls: TlsConfig, rustls: RustlsConfig) {
+    let modified = |tls: &TlsConfig| -> Vec<Option<std::time::SystemTime>> {
+        [Some(&tls.cert), Some(&tls.key), tls.client_ca.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+            .collect()
+    };
+    tokio::spawn(async move {
+        let mut last = modified(&tls);
+        let mut interval = tokio::time::interval(WATCH_INTERVAL);
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let current = modified(&tls);
+            if current != last {
+                last = current;
+                reload_and_log(&tls, &rustls);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    struct Fixture {
+        dir: PathBuf,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("tls-{}-{}", std::process::id(), name));
+            std::fs::create_dir_all(&dir).unwrap();
+            Fixture { dir }
+        }
+
+        // Gera um par autoassinado e grava certificado e chave com o prefixo dado
+        fn pair(&self, prefix: &str) -> (PathBuf, PathBuf) {
+            let generated = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+            let cert = self.write(&format!("{}.pem", prefix), &generated.cert.pem());
+            let key = self.write(&format!("{}.key", prefix), &generated.signing_key.serialize_pem());
+            (cert, key)
+        }
+
+        fn write(&self, name: &str, content: &str) -> PathBuf {
+            let path = self.dir.join(name);
+            std::fs::write(&path, content).unwrap();
+            path
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    fn tls_config(cert: PathBuf, key: PathBuf, client_ca: Option<PathBuf>) -> TlsConfig {
+        TlsConfig { cert, key, client_ca }
+    }
+
+    #[test]
+    fn accepts_matching_pair_and_client_ca() {
+        let fixture = Fixture::new("valid");
+        let (cert, key) = fixture.pair("server");
+        let (ca, _) = fixture.pair("ca");
+        let config = server_config(&tls_config(cert.clone(), key.clone(), None)).unwrap();
+        assert_eq!(config.alpn_protocols, vec![b"h2".to_vec(), b"http/1.1".to_vec()]);
+        assert!(server_config(&tls_config(cert, key, Some(ca))).is_ok());
+    }
+
+    #[test]
+    fn rejects_mismatched_key() {
+        let fixture = Fixture::new("mismatch");
+        let (cert, _) = fixture.pair("server");
+        let (_, other_key) = fixture.pair("other");
+        let error = format!("{:#}", server_config(&tls_config(cert, other_key, None)).unwrap_err());
+        assert!(error.contains("não formam um par válido"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_bad_client_ca() {
+        let fixture = Fixture::new("bad-ca");
+        let (cert, key) = fixture.pair("server");
+        let empty = fixture.write("empty.pem", "");
+        let error = format!("{:#}", server_config(&tls_config(cert.clone(), key.clone(), Some(empty))).unwrap_err());
+        assert!(error.contains("nenhum certificado"), "{}", error);
+        let missing = fixture.dir.join("missing.pem");
+        assert!(server_config(&tls_config(cert, key, Some(missing))).is_err());
+    }
+
+    #[tokio::test]
+    async fn failed_reload_keeps_current_config() {
+        let fixture = Fixture::new("reload");
+        let (cert, key) = fixture.pair("server");
+        let tls = tls_config(cert, key, None);
+        let rustls = load(&tls).unwrap();
+        let before = rustls.get_inner();
+
+        fixture.write("server.pem", "não é PEM");
+        assert!(reload(&tls, &rustls).is_err());
+        assert!(Arc::ptr_eq(&before, &rustls.get_inner()));
+
+        fixture.pair("server");
+        reload(&tls, &rustls).unwrap();
+        assert!(!Arc::ptr_eq(&before, &rustls.get_inner()));
+    }
+}
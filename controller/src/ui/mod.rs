@@ -0,0 +1,136 @@
+// Módulo para servir o build de produção do view/ (diretório em disco ou embutido no binário)
+use axum::body::Body;
+use axum::extract::Request;
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use std::borrow::Cow;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+// Arquivos em assets/ têm hash no nome (gerado pelo Vite) e podem ficar em cache indefinidamente
+const IMMUTABLE: &str = "public, max-age=31536000, immutable";
+const REVALIDATE: &str = "no-cache";
+
+#[cfg(feature = "embed-ui")]
+#[derive(rust_embed::RustEmbed)]
+#[folder = "../view/dist"]
+struct Embedded;
+
+#[derive(Debug, Clone)]
+pub enum UiSource {
+    Dir(PathBuf),
+    #[cfg(feature = "embed-ui")]
+    Embedded,
+}
+
+impl UiSource {
+    // Diretório configurado tem precedência; sem ele usa o build embutido, se compilado com embed-ui
+    pub fn resolve(dir: Option<&Path>) -> Option<UiSource> {
+        match dir {
+            Some(dir) => Some(UiSource::Dir(dir.to_path_buf())),
+            #[cfg(feature = "embed-ui")]
+            None => Some(UiSource::Embedded),
+            #[cfg(not(feature = "embed-ui"))]
+            None => None,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            UiSource::Dir(dir) => dir.display().to_string(),
+            #[cfg(feature = "embed-ui")]
+            UiSource::Embedded => "embutido no binário".to_string(),
+        }
+    }
+
+    async fn get(&self, path: &str) -> Option<Cow<'static, [u8]>> {
+        match self {
+            UiSource::Dir(dir) => {
+                // Só aceita componentes normais para não sair do diretório (ex.: "../")
+                let relative = Path::new(path);
+                if !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+                    return None;
+                }
+                let full = dir.join(relative);
+                if !tokio::fs::metadata(&full).await.ok()?.is_file() {
+                    return None;
+                }
+                tokio::fs::read(full).await.ok().map(Cow::Owned)
+            }
+            #[cfg(feature = "embed-ui")]
+            UiSource::Embedded => Embedded::get(path).map(|f| f.data),
+        }
+    }
+}
+
+fn asset_response(path: &str, data: Cow<'static, [u8]>) -> Response {
+    let mime = mime_guess::from_path(path).first_or_octet_stream();
+    let cache = if path.starts_with("assets/") { IMMUTABLE } else { REVALIDATE };
+    let mut response = Response::new(Body::from(data));
+    let headers = response.headers_mut();
+    if let Ok(value) = HeaderValue::from_str(mime.as_ref()) {
+        headers.insert(header::CONTENT_TYPE, value);
+    }
+    headers.insert(header::CACHE_CONTROL, HeaderValue::from_static(cache));
+    response
+}
+
+// Fallback do Router: entrega o arquivo pedido ou, para rotas do SPA, o index.html
+pub async fn serve(source: Arc<UiSource>, request: Request) -> Response {
+    let path = request.uri().path().trim_start_matches('/');
+    // Rotas desconhecidas da API continuam sendo 404, sem cair no SPA
+    if path == "api" || path.starts_with("api/") {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let path = if path.is_empty() { "index.html" } else { path };
+    if let Some(data) = source.get(path).await {
+        return asset_response(path, data);
+    }
+    // Arquivos com extensão que não existem são 404; o resto é rota do client-side router
+    let is_file = Path::new(path).extension().is_some();
+    match source.get("index.html").await {
+        Some(index) if !is_file => asset_response("index.html", index),
+        _ => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    // O build fica em dist/ e config.toml logo acima, fora do diretório servido
+    async fn get(root: &TempDir, uri: &str) -> (StatusCode, Vec<u8>) {
+        let source = Arc::new(UiSource::Dir(root.path().join("dist")));
+        let response = serve(source, Request::builder().uri(uri).body(Body::empty()).unwrap()).await;
+        let status = response.status();
+        (status, axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap().to_vec())
+    }
+
+    fn root() -> TempDir {
+        TempDir::with_files(
+            "ui",
+            &[("config.toml", "[auth]\nenabled = false\n"), ("dist/index.html", "<html>index</html>"), ("dist/assets/app-1a2b.js", "console.log(1)")],
+        )
+    }
+
+    #[tokio::test]
+    async fn does_not_escape_the_ui_dir() {
+        let root = root();
+        for uri in ["/../config.toml", "/assets/../../config.toml", "/%2e%2e/config.toml", "/%2E%2E%2Fconfig.toml", "/..%2fconfig.toml"] {
+            let (status, body) = get(&root, uri).await;
+            assert_eq!(status, StatusCode::NOT_FOUND, "{}", uri);
+            assert!(!String::from_utf8_lossy(&body).contains("[auth]"), "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn serves_assets_spa_routes_and_api_404() {
+        let root = root();
+        assert_eq!(get(&root, "/assets/app-1a2b.js").await, (StatusCode::OK, b"console.log(1)".to_vec()));
+        // Rota do client-side router cai no index.html; arquivo inexistente e API desconhecida são 404
+        assert_eq!(get(&root, "/processes/42").await, (StatusCode::OK, b"<html>index</html>".to_vec()));
+        assert_eq!(get(&root, "/missing.js").await.0, StatusCode::NOT_FOUND);
+        assert_eq!(get(&root, "/api/unknown").await.0, StatusCode::NOT_FOUND);
+    }
+}
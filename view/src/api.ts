@@ -0,0 +1,25 @@
+// Acesso à API do controller enviando o token bearer guardado no navegador (necessário com [auth] habilitado)
+const TOKEN_KEY = 'os-dashboard-token';
+
+// Lançado quando a API responde 401: o Dashboard pede um token e tenta de novo
+export class UnauthorizedError extends Error {
+    constructor() {
+        super('Token ausente ou inválido');
+    }
+}
+
+export const getToken = (): string | null => localStorage.getItem(TOKEN_KEY);
+
+// Token vazio remove o salvo (volta a acessar sem autenticação)
+export const setToken = (token: string) => {
+    if (token.trim()) localStorage.setItem(TOKEN_KEY, token.trim());
+    else localStorage.removeItem(TOKEN_KEY);
+};
+
+export async function apiFetch<T>(path: string): Promise<T> {
+    const token = getToken();
+    const res = await fetch(path, { headers: token ? { Authorization: `Bearer ${token}` } : {} });
+    if (res.status === 401) throw new UnauthorizedError();
+    if (!res.ok) throw new Error(`${path}: HTTP ${res.status}`);
+    return (await res.json()) as T;
+}
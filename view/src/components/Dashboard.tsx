@@ -1,21 +1,18 @@
 ﻿// Importa hooks do React e componentes do Material UI
 import React, { useState, useEffect } from 'react';
 import { Tabs, Tab, Box, Typography } from '@mui/material';
-// Componente para exibir as partições do sistema
+import { apiFetch, UnauthorizedError } from '../api';
+import TokenDialog from './TokenDialog';
+// Componente para exibir as partições do sistema (formato de /api/v2/filesystem/partitions)
 interface PartitionInfo {
-    name: string; // DeviceID, ex: "Disk #0, Partition #1"
-    drive_letter?: string | null; // Ex: "C:"
+    name: string; // Ex: "C:\" no Windows, dispositivo de origem no Linux
     total_bytes: number;
     free_bytes: number;
     used_bytes: number;
     percent_used: number;
-    partition_type?: string;
-    is_boot?: boolean;
-    is_bootable?: boolean;
-    is_primary?: boolean;
-    disk_index?: number;
-    starting_offset?: number;
-    is_hidden?: boolean;
+    mount_point?: string | null;
+    fs_type?: string | null;
+    kind?: 'block' | 'bind' | 'overlay' | 'tmpfs' | 'network' | 'pseudo' | 'other' | null;
 }
 
 const formatPartitionBytes = (bytes: number) => {
@@ -25,24 +22,23 @@ const formatPartitionBytes = (bytes: number) => {
     return `${(bytes / 1024 / 1024 / 1024).toFixed(2)} GB`;
 };
 
-const PartitionTable: React.FC = () => {
+const PartitionTable: React.FC<{ onUnauthorized: () => void }> = ({ onUnauthorized }) => {
     const [partitions, setPartitions] = useState<PartitionInfo[]>([]);
     const [loading, setLoading] = useState(true);
     const [error, setError] = useState<string | null>(null);
 
     useEffect(() => {
-        fetch('/api/filesystem/partitions')
-            .then(res => {
-                if (!res.ok) throw new Error('Erro ao buscar partições');
-                return res.json();
-            })
+        apiFetch<PartitionInfo[]>('/api/v2/filesystem/partitions')
             .then(data => {
                 setPartitions(Array.isArray(data) ? data : []);
                 setError(null);
             })
-            .catch(() => setError('Não foi possível buscar as partições.'))
+            .catch(err => {
+                if (err instanceof UnauthorizedError) onUnauthorized();
+                setError('Não foi possível buscar as partições.');
+            })
             .finally(() => setLoading(false));
-    }, []);
+    }, [onUnauthorized]);
 
     if (loading) return <div style={{ marginBottom: 16 }}>Carregando partições...</div>;
     if (error) return <div style={{ color: 'red', marginBottom: 16 }}>{error}</div>;
@@ -55,11 +51,9 @@ const PartitionTable: React.FC = () => {
                 <thead>
                     <tr>
                         <th style={{ textAlign: 'left', padding: 8 }}>Nome</th>
-                        <th style={{ textAlign: 'left', padding: 8 }}>Letra</th>
+                        <th style={{ textAlign: 'left', padding: 8 }}>Montagem</th>
+                        <th style={{ textAlign: 'left', padding: 8 }}>Sistema de Arquivos</th>
                         <th style={{ textAlign: 'left', padding: 8 }}>Tipo</th>
-                        <th style={{ textAlign: 'center', padding: 8 }}>Boot</th>
-                        <th style={{ textAlign: 'center', padding: 8 }}>Primária</th>
-                        <th style={{ textAlign: 'center', padding: 8 }}>Oculta</th>
                         <th style={{ textAlign: 'right', padding: 8 }}>Tamanho Total</th>
                         <th style={{ textAlign: 'right', padding: 8 }}>Usado</th>
                         <th style={{ textAlign: 'right', padding: 8 }}>Livre</th>
@@ -68,15 +62,11 @@ const PartitionTable: React.FC = () => {
                 </thead>
                 <tbody>
                     {partitions.map(part => (
-                        <tr key={part.name + (part.drive_letter ?? '')} style={{ borderBottom: '1px solid #eee', background: part.is_hidden ? '#f9f9f9' : undefined }}>
+                        <tr key={part.name + (part.mount_point ?? '')} style={{ borderBottom: '1px solid #eee' }}>
                             <td style={{ padding: 8 }}>{part.name}</td>
-                            <td style={{ padding: 8 }}>{part.drive_letter ?? <span style={{ color: '#aaa' }}>-</span>}</td>
-                            <td style={{ padding: 8 }}>{part.partition_type ?? <span style={{ color: '#aaa' }}>-</span>}</td>
-                            <td style={{ textAlign: 'center', padding: 8 }}>{part.is_boot ? '✔️' : ''}</td>
-                            <td style={{ textAlign: 'center', padding: 8 }}>{part.is_primary ? '✔️' : ''}</td>
-                            <td style={{ textAlign: 'center', padding: 8 }}>
-                                {part.is_hidden ? <span title="Partição Oculta" style={{ color: '#e67e22', fontWeight: 700 }}>Oculta</span> : ''}
-                            </td>
+                            <td style={{ padding: 8 }}>{part.mount_point ?? <span style={{ color: '#aaa' }}>-</span>}</td>
+                            <td style={{ padding: 8 }}>{part.fs_type ?? <span style={{ color: '#aaa' }}>-</span>}</td>
+                            <td style={{ padding: 8 }}>{part.kind ?? <span style={{ color: '#aaa' }}>-</span>}</td>
                             <td style={{ textAlign: 'right', padding: 8 }}>{formatPartitionBytes(part.total_bytes)}</td>
                             <td style={{ textAlign: 'right', padding: 8 }}>{formatPartitionBytes(part.used_bytes)}</td>
                             <td style={{ textAlign: 'right', padding: 8 }}>{formatPartitionBytes(part.free_bytes)}</td>
@@ -93,7 +83,7 @@ import DiskMonitor from './DiskMonitor';
 // Importa componentes customizados do projeto
 import MetricCard from './MetricCard';
 import Chart from './Chart';
-import ProcessList from './ProcessList';
+import ProcessList, { type ProcessSummary } from './ProcessList';
 
 // ====== VARIÁVEIS DE TAMANHO DE FONTE AJUSTÁVEIS ======
 export const CARD_TITLE_FONT_SIZE = 16; // px - Tamanho do título dos cards
@@ -119,15 +109,15 @@ export type DiskInfo = {
     write_bytes: number;
     transfer_bytes: number;
 };
+// Formato de /api/v2/system: percentuais numéricos e uptime em segundos
 export type SystemInfo = {
-    cpu_total: string;
+    cpu_total: number;
     cpu_per_core: number[];
     memory_total_mb: number;
     memory_used_mb: number;
     memory_free_mb: number;
-    memory_percent: string;
-    uptime: string;
-    uptime_secs?: number;
+    memory_percent: number;
+    uptime_secs: number;
     process_count: number;
     cpu_base_speed_mhz?: number;
     cpu_logical_processors: number;
@@ -142,45 +132,12 @@ export type SystemInfo = {
     boot_time?: number;
 };
 
-// Tipagem das informações de cada processo (com recursos abertos e threads)
-export type ThreadInfo = {
-    tid: number;
-    base_priority: number;
-    delta_priority: number;
-    start_address: number;
-    state: string;
-    wait_reason: string;
-    context_switches?: number;
-    user_time_ms?: number;
-    kernel_time_ms?: number;
-};
-export type ProcessInfo = {
-    pid: number;
-    name: string;
-    exe_path?: string;
-    status: string;
-    username: string;
-    cpu: number;
-    memory_kb: number;
-    arch: string;
-    description: string;
-    page_faults: number;
-    peak_working_set_kb: number;
-    working_set_kb: number;
-    pagefile_kb: number;
-    io_read_bytes?: number;
-    io_write_bytes?: number;
-    io_read_ops?: number;
-    io_write_ops?: number;
-    handle_count?: number;
-    thread_count?: number;
-    parent_pid?: number;
-    priority?: number;
-    creation_time?: number;
-    session_id?: number;
-    command_line?: string;
-    environment?: string[];
-    threads: ThreadInfo[];
+// "1d 02:03:04" a partir de uptime_secs
+const formatUptime = (secs: number) => {
+    const days = Math.floor(secs / 86400);
+    const pad = (n: number) => String(n).padStart(2, '0');
+    const time = `${pad(Math.floor((secs % 86400) / 3600))}:${pad(Math.floor((secs % 3600) / 60))}:${pad(secs % 60)}`;
+    return days > 0 ? `${days}d ${time}` : time;
 };
 
 // Quantidade de pontos no histórico dos gráficos
@@ -194,35 +151,35 @@ export default function Dashboard() {
     const [systemInfo, setSystemInfo] = useState<SystemInfo | null>(null);
     const [apiError, setApiError] = useState<string | null>(null);
     // Estado com lista de processos
-    const [processes, setProcesses] = useState<ProcessInfo[]>([]);
+    const [processes, setProcesses] = useState<ProcessSummary[]>([]);
     // Histórico de uso de CPU (para o gráfico)
     const [cpuHistory, setCpuHistory] = useState<{ name: string; value: number }[]>([]);
     // Histórico de uso de memória (para o gráfico)
     const [memHistory, setMemHistory] = useState<{ name: string; value: number }[]>([]);
     // Histórico de discos (um array por disco)
     const [diskHistory, setDiskHistory] = useState<Record<string, { time: string; read: number; write: number; readB: number; writeB: number }[]>>({});
+    // Com [auth] habilitado no controller, um 401 abre o pedido de token; salvar um token refaz as buscas
+    const [needsToken, setNeedsToken] = useState<boolean>(false);
+    const [tokenVersion, setTokenVersion] = useState<number>(0);
+    const onUnauthorized = React.useCallback(() => setNeedsToken(true), []);
 
     // Todas as funções de normalização removidas. O frontend agora usa os dados do backend exatamente como recebidos (snake_case).
 
     useEffect(() => {
         let lastDiskStats: Record<string, { read: number; write: number; readB: number; writeB: number }> = {};
         const fetchData = () => {
-            fetch('/api/system')
-                .then(res => {
-                    if (!res.ok) throw new Error('Erro ao buscar dados do sistema');
-                    return res.json();
-                })
+            apiFetch<SystemInfo>('/api/v2/system')
                 .then(data => {
-                    setSystemInfo(data as SystemInfo);
+                    setSystemInfo(data);
                     setApiError(null);
                     // Atualiza histórico de CPU
-                    const cpuValue = parseFloat(String(data.cpu_total));
+                    const cpuValue = data.cpu_total;
                     setCpuHistory(prev => {
                         const next = [...prev, { name: new Date().toLocaleTimeString(), value: cpuValue }];
                         return next.length > HISTORY_LENGTH ? next.slice(-HISTORY_LENGTH) : next;
                     });
                     // Atualiza histórico de memória
-                    const memValue = parseFloat(String(data.memory_percent));
+                    const memValue = data.memory_percent;
                     setMemHistory(prev => {
                         const next = [...prev, { name: new Date().toLocaleTimeString(), value: memValue }];
                         return next.length > HISTORY_LENGTH ? next.slice(-HISTORY_LENGTH) : next;
@@ -248,21 +205,26 @@ export default function Dashboard() {
                         setDiskHistory(updated);
                     }
                 })
-                .catch(() => {
+                .catch(err => {
+                    if (err instanceof UnauthorizedError) {
+                        setNeedsToken(true);
+                        return;
+                    }
                     setApiError('Não foi possível conectar ao backend ou a API retornou erro.');
                     // Não limpa o último dado válido!
                 });
-            fetch('/api/processes')
-                .then(res => res.json())
-                .then(data => setProcesses(Array.isArray(data) ? (data as ProcessInfo[]) : []))
-                .catch(() => {
-                    // Se a API falhar, não faz nada (mantém o último estado)
+            // Lista resumida; os detalhes de cada processo são buscados ao abrir o diálogo
+            apiFetch<ProcessSummary[]>('/api/v2/processes')
+                .then(data => setProcesses(Array.isArray(data) ? data : []))
+                .catch(err => {
+                    // Se a API falhar, mantém o último estado
+                    if (err instanceof UnauthorizedError) setNeedsToken(true);
                 });
         };
         fetchData();
         const interval = setInterval(fetchData, 5000); // Atualiza a cada 5 segundos conforme requisito
         return () => clearInterval(interval);
-    }, []);
+    }, [tokenVersion]);
 
     return (
         <Box
@@ -300,6 +262,13 @@ export default function Dashboard() {
                     <Tab label="Disco" sx={{ fontSize: TAB_FONT_SIZE, fontWeight: 600 }} />
                     {/* <Tab label="Diretórios" sx={{ fontSize: TAB_FONT_SIZE, fontWeight: 600 }} /> */}
                 </Tabs>
+                <TokenDialog
+                    open={needsToken}
+                    onSaved={() => {
+                        setNeedsToken(false);
+                        setTokenVersion(v => v + 1);
+                    }}
+                />
                 {/* Alerta de erro persistente se a API estiver offline */}
                 {apiError && (
                     <Box sx={{ mb: 2, p: 2, background: '#2d2d2d', borderRadius: 2, color: '#e53935', fontWeight: 600, fontSize: 18, textAlign: 'center' }}>
@@ -316,7 +285,7 @@ export default function Dashboard() {
                                 {/* Cartão de uso total de CPU com gráfico de linha */}
                                 <MetricCard
                                     title="Uso Total de CPU"
-                                    value={systemInfo.cpu_total}
+                                    value={`${systemInfo.cpu_total.toFixed(2)}%`}
                                     accent
                                     titleFontSize={CARD_TITLE_FONT_SIZE}
                                     valueFontSize={CARD_VALUE_FONT_SIZE}
@@ -333,7 +302,7 @@ export default function Dashboard() {
                                 {/* Cartão de uso de memória com gráfico de linha */}
                                 <MetricCard
                                     title="Uso de Memória (%)"
-                                    value={systemInfo.memory_percent}
+                                    value={`${systemInfo.memory_percent.toFixed(2)}%`}
                                     accent
                                     titleFontSize={CARD_TITLE_FONT_SIZE}
                                     valueFontSize={CARD_VALUE_FONT_SIZE}
@@ -359,7 +328,7 @@ export default function Dashboard() {
                                         width: '100%',
                                     }}
                                 >
-                                    <MetricCard title="Uptime" value={systemInfo.uptime} small titleFontSize={CARD_TITLE_FONT_SIZE} valueFontSize={CARD_VALUE_FONT_SIZE} sx={{ fontSize: CARD_VALUE_FONT_SIZE, minWidth: CARD_MIN_WIDTH, minHeight: CARD_MIN_HEIGHT }} />
+                                    <MetricCard title="Uptime" value={formatUptime(systemInfo.uptime_secs)} small titleFontSize={CARD_TITLE_FONT_SIZE} valueFontSize={CARD_VALUE_FONT_SIZE} sx={{ fontSize: CARD_VALUE_FONT_SIZE, minWidth: CARD_MIN_WIDTH, minHeight: CARD_MIN_HEIGHT }} />
                                     <MetricCard title="Memória Total" value={`${systemInfo.memory_total_mb} MB`} small titleFontSize={CARD_TITLE_FONT_SIZE} valueFontSize={CARD_VALUE_FONT_SIZE} sx={{ fontSize: CARD_VALUE_FONT_SIZE, minWidth: CARD_MIN_WIDTH, minHeight: CARD_MIN_HEIGHT }} />
                                     <MetricCard title="Memória Usada" value={`${systemInfo.memory_used_mb} MB`} small titleFontSize={CARD_TITLE_FONT_SIZE} valueFontSize={CARD_VALUE_FONT_SIZE} sx={{ fontSize: CARD_VALUE_FONT_SIZE, minWidth: CARD_MIN_WIDTH, minHeight: CARD_MIN_HEIGHT }} />
                                     <MetricCard title="Memória Livre" value={`${systemInfo.memory_free_mb} MB`} small titleFontSize={CARD_TITLE_FONT_SIZE} valueFontSize={CARD_VALUE_FONT_SIZE} sx={{ fontSize: CARD_VALUE_FONT_SIZE, minWidth: CARD_MIN_WIDTH, minHeight: CARD_MIN_HEIGHT }} />
@@ -406,13 +375,13 @@ export default function Dashboard() {
                 {/* Aba de Processos */}
                 {tab === 1 && (
                     <Box sx={{ width: '100%', display: 'flex', justifyContent: 'center' }}>
-                        <ProcessList processes={processes} memoryTotalKb={systemInfo?.memory_total_mb ? systemInfo.memory_total_mb * 1024 : undefined} />
+                        <ProcessList processes={processes} memoryTotalKb={systemInfo?.memory_total_mb ? systemInfo.memory_total_mb * 1024 : undefined} onUnauthorized={onUnauthorized} />
                     </Box>
                 )}
                 {/* Aba de Disco */}
                 {tab === 2 && (
                     <>
-                        <PartitionTable />
+                        <PartitionTable key={tokenVersion} onUnauthorized={onUnauthorized} />
                         <DiskMonitor
                             disks={Array.isArray(systemInfo?.disks) ? systemInfo.disks.filter(disk => disk && typeof disk === 'object' && disk.name) : []}
                             diskHistory={diskHistory}
@@ -1,12 +1,13 @@
 ﻿import { useState } from 'react';
 import { Table, TableBody, TableCell, TableContainer, TableHead, TableRow, Paper, Typography, TableSortLabel, Button, Dialog, DialogTitle, DialogContent, DialogActions, List, ListItem, ListItemText } from '@mui/material';
+import { apiFetch, UnauthorizedError } from '../api';
 
 // ====== VARIÁVEIS DE TAMANHO DE FONTE AJUSTÁVEIS ======
 export const PROCESS_TITLE_FONT_SIZE = 20; // px
 export const PROCESS_ROW_FONT_SIZE = 14;   // px
 // ======================================================
 
-// Tipagem fiel ao backend
+// Tipagem fiel ao backend (/api/v2)
 export type ThreadInfo = {
     tid: number;
     base_priority: number;
@@ -18,6 +19,26 @@ export type ThreadInfo = {
     user_time_ms?: number;
     kernel_time_ms?: number;
 };
+// Item de /api/v2/processes
+export type ProcessSummary = {
+    pid: number;
+    name: string;
+    exe_path?: string | null;
+    status: string;
+    username: string;
+    cpu: number;
+    memory_kb: number;
+    memory_percent: number;
+    handle_count?: number | null;
+    thread_count?: number | null;
+    parent_pid?: number | null;
+    creation_time?: number | null;
+};
+export type OpenResource = {
+    kind: 'file' | 'mutex' | 'semaphore' | 'pipe' | 'socket' | 'other';
+    handle: number;
+};
+// Detalhe de /api/v2/processes/{pid}; environment só vem para tokens admin
 export type ProcessInfo = {
     pid: number;
     name: string;
@@ -43,24 +64,25 @@ export type ProcessInfo = {
     creation_time?: number;
     session_id?: number;
     command_line?: string;
-    environment?: string[];
+    environment?: string[] | null;
     threads: ThreadInfo[];
-    open_resources?: string[];
-    memory_percent?: number;
+    open_resources?: OpenResource[] | null;
+    memory_percent: number;
 };
 
 type ProcessListProps = {
-    readonly processes: ProcessInfo[];
+    readonly processes: ProcessSummary[];
     readonly memoryTotalKb?: number;
+    readonly onUnauthorized?: () => void;
 };
 
 type Order = 'asc' | 'desc';
-type OrderBy = keyof ProcessInfo | 'memory_percent';
+type OrderBy = keyof ProcessSummary;
 
 // Função para comparar dois itens para ordenação decrescente
 function descendingComparator(
-    a: ProcessInfo,
-    b: ProcessInfo,
+    a: ProcessSummary,
+    b: ProcessSummary,
     orderBy: OrderBy,
     memoryTotalKb?: number
 ) {
@@ -69,20 +91,20 @@ function descendingComparator(
         const percentB = b.memory_kb && memoryTotalKb ? b.memory_kb / memoryTotalKb : 0;
         return percentB - percentA;
     }
-    if (a[orderBy as keyof ProcessInfo] === undefined) return 1;
-    if (b[orderBy as keyof ProcessInfo] === undefined) return -1;
+    if (a[orderBy as keyof ProcessSummary] === undefined) return 1;
+    if (b[orderBy as keyof ProcessSummary] === undefined) return -1;
     if (
-        typeof a[orderBy as keyof ProcessInfo] === 'number' &&
-        typeof b[orderBy as keyof ProcessInfo] === 'number'
+        typeof a[orderBy as keyof ProcessSummary] === 'number' &&
+        typeof b[orderBy as keyof ProcessSummary] === 'number'
     ) {
-        return (b[orderBy as keyof ProcessInfo] as number) - (a[orderBy as keyof ProcessInfo] as number);
+        return (b[orderBy as keyof ProcessSummary] as number) - (a[orderBy as keyof ProcessSummary] as number);
     }
     if (
-        typeof a[orderBy as keyof ProcessInfo] === 'string' &&
-        typeof b[orderBy as keyof ProcessInfo] === 'string'
+        typeof a[orderBy as keyof ProcessSummary] === 'string' &&
+        typeof b[orderBy as keyof ProcessSummary] === 'string'
     ) {
-        return (b[orderBy as keyof ProcessInfo] as string).localeCompare(
-            a[orderBy as keyof ProcessInfo] as string
+        return (b[orderBy as keyof ProcessSummary] as string).localeCompare(
+            a[orderBy as keyof ProcessSummary] as string
         );
     }
     return 0;
@@ -93,16 +115,19 @@ function getComparator(
     order: Order,
     orderBy: OrderBy,
     memoryTotalKb?: number
-): (a: ProcessInfo, b: ProcessInfo) => number {
+): (a: ProcessSummary, b: ProcessSummary) => number {
     return order === 'desc'
         ? (a, b) => descendingComparator(a, b, orderBy, memoryTotalKb)
         : (a, b) => -descendingComparator(a, b, orderBy, memoryTotalKb);
 }
 
-export default function ProcessList({ processes, memoryTotalKb }: ProcessListProps) {
+export default function ProcessList({ processes, memoryTotalKb, onUnauthorized }: ProcessListProps) {
     const [order, setOrder] = useState<Order>('asc');
     const [orderBy, setOrderBy] = useState<OrderBy>('pid');
+    // Linha clicada (título do diálogo) e o detalhe buscado em /api/v2/processes/{pid}
+    const [selectedSummary, setSelectedSummary] = useState<ProcessSummary | null>(null);
     const [selectedProcess, setSelectedProcess] = useState<ProcessInfo | null>(null);
+    const [detailsError, setDetailsError] = useState<string | null>(null);
     const [dialogOpen, setDialogOpen] = useState(false);
 
     // Função chamada ao clicar para ordenar uma coluna
@@ -139,13 +164,22 @@ export default function ProcessList({ processes, memoryTotalKb }: ProcessListPro
         return '-';
     };
 
-    // Função para abrir detalhes do processo
-    const handleOpenDetails = (proc: ProcessInfo) => {
-        setSelectedProcess(proc);
+    // Função para abrir detalhes do processo: a lista não traz threads, recursos nem linha de comando
+    const handleOpenDetails = (proc: ProcessSummary) => {
+        setSelectedSummary(proc);
+        setSelectedProcess(null);
+        setDetailsError(null);
         setDialogOpen(true);
+        apiFetch<ProcessInfo>(`/api/v2/processes/${proc.pid}`)
+            .then(setSelectedProcess)
+            .catch(err => {
+                if (err instanceof UnauthorizedError) onUnauthorized?.();
+                setDetailsError('Não foi possível buscar os detalhes (o processo pode ter sido encerrado).');
+            });
     };
     const handleCloseDialog = () => {
         setDialogOpen(false);
+        setSelectedSummary(null);
         setSelectedProcess(null);
     };
 
@@ -277,8 +311,11 @@ export default function ProcessList({ processes, memoryTotalKb }: ProcessListPro
 
             {/* Diálogo de detalhes do processo */}
             <Dialog open={dialogOpen} onClose={handleCloseDialog} maxWidth="md" fullWidth>
-                <DialogTitle>Detalhes do Processo PID {selectedProcess?.pid} - {selectedProcess?.name}</DialogTitle>
+                <DialogTitle>Detalhes do Processo PID {selectedSummary?.pid} - {selectedSummary?.name}</DialogTitle>
                 <DialogContent dividers>
+                    {!selectedProcess && (
+                        <Typography color={detailsError ? 'error' : 'text.secondary'}>{detailsError ?? 'Carregando detalhes...'}</Typography>
+                    )}
                     {selectedProcess && (
                         <>
                             <Typography variant="subtitle1" sx={{ mb: 1 }}>Informações Gerais</Typography>
@@ -304,9 +341,9 @@ export default function ProcessList({ processes, memoryTotalKb }: ProcessListPro
                             <Typography variant="subtitle1" sx={{ mt: 2, mb: 1 }}>Recursos Abertos / Arquivos</Typography>
                             <List dense>
                                 {Array.isArray(selectedProcess.open_resources) && selectedProcess.open_resources.length > 0 ? (
-                                    selectedProcess.open_resources.map((path, idx) => (
+                                    selectedProcess.open_resources.map((resource, idx) => (
                                         <ListItem key={idx}>
-                                            <ListItemText primary={path} />
+                                            <ListItemText primary={`${resource.kind} (handle 0x${resource.handle.toString(16)})`} />
                                         </ListItem>
                                     ))
                                 ) : (
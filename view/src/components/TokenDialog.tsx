@@ -0,0 +1,40 @@
+import { useState } from 'react';
+import { Button, Dialog, DialogActions, DialogContent, DialogTitle, TextField, Typography } from '@mui/material';
+import { getToken, setToken } from '../api';
+
+// Pede o token de acesso quando a API responde 401; o valor fica no localStorage deste navegador
+export default function TokenDialog({ open, onSaved }: { open: boolean; onSaved: () => void }) {
+    const [value, setValue] = useState<string>(getToken() ?? '');
+    const save = (token: string) => {
+        setToken(token);
+        onSaved();
+    };
+    return (
+        <Dialog open={open} maxWidth="sm" fullWidth>
+            <DialogTitle>Token de acesso</DialogTitle>
+            <DialogContent>
+                <Typography variant="body2" sx={{ mb: 2 }}>
+                    {getToken() ? 'O token salvo foi recusado pelo controller.' : 'O controller exige autenticação.'} Informe um token
+                    configurado em auth.tokens (papel viewer ou admin).
+                </Typography>
+                <TextField
+                    autoFocus
+                    fullWidth
+                    type="password"
+                    label="Token"
+                    value={value}
+                    onChange={e => setValue(e.target.value)}
+                    onKeyDown={e => {
+                        if (e.key === 'Enter' && value.trim()) save(value);
+                    }}
+                />
+            </DialogContent>
+            <DialogActions>
+                <Button onClick={() => save('')}>Limpar</Button>
+                <Button variant="contained" disabled={!value.trim()} onClick={() => save(value)}>
+                    Salvar
+                </Button>
+            </DialogActions>
+        </Dialog>
+    );
+}
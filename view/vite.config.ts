@@ -5,7 +5,7 @@ export default defineConfig({
   plugins: [react()],
   server: {
     proxy: {
-      '/api': 'http://localhost:3001'
+      '/api': 'http://127.0.0.1:3001'
     }
   }
 })
\ No newline at end of file